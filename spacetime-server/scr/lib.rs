@@ -1,5 +1,8 @@
 // SpacetimeDB imports
-use spacetimedb::{table, reducer, ReducerContext, Identity, Table, Timestamp, ScheduleAt, SpacetimeType};
+use spacetimedb::{table, reducer, client_visibility_filter, Filter, ReducerContext, Identity, Table, Timestamp, ScheduleAt, SpacetimeType};
+use sha2::{Digest, Sha256};
+use serde_json::{json, Value};
+use std::collections::HashMap;
 use std::time::Duration;
 
 // ---------- Custom Types ----------
@@ -20,6 +23,11 @@ pub enum DocType {
     Claims,
     Drawings,
     Abstract,
+    InventorDeclaration,
+    ApplicationDataSheet,
+    InformationDisclosureStatement,
+    PowerOfAttorney,
+    SequenceListing,
 }
 
 #[derive(SpacetimeType, Clone, Debug, PartialEq)]
@@ -68,6 +76,202 @@ pub enum TrendMetric {
     ActiveSessionCount,
 }
 
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum OfficeActionType {
+    NonFinal,
+    Final,
+    Restriction,
+    NoticeOfAllowance,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum EntitySize {
+    Large,
+    Small,
+    Micro,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum FilingType {
+    Provisional,
+    NonProvisional,
+    Pct,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum DeadlineKind {
+    ProvisionalConversion,
+    PctNationalPhase,
+    OfficeActionResponse,
+    OfficeActionStatutoryBar,
+    MaintenanceFee,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum DocJobStatus {
+    Queued,
+    Leased,
+    Completed,
+    Failed, // out of attempts
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum ReferenceKind {
+    UsPatent,
+    UsPublication,
+    ForeignPatent,
+    NonPatentLiterature,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct MerkleProofStep {
+    pub sibling: String, // hex node hash
+    pub sibling_is_left: bool,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct MerkleLeaf {
+    pub notarization_id: u64,
+    pub application_id: u64,
+    pub leaf_hash: String, // hex
+    pub proof: Vec<MerkleProofStep>, // from the leaf up to the root
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum ImportFormat {
+    Json,
+    Csv,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct ImportRowError {
+    pub row: u32, // 1-based index of the data row in the payload
+    pub message: String,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum ExportFormat {
+    St96Xml,
+    PortfolioJson,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum DiffOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct DiffSegment {
+    pub op: DiffOp,
+    pub text: String,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum ClassificationScheme {
+    Ipc,
+    Cpc,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum ClassificationSource {
+    Suggested,
+    Confirmed,
+    Manual,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum DeclarationStatus {
+    Pending,
+    Confirmed,
+    Disputed,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum PatentOffice {
+    Uspto,
+    Epo,
+    Jpo,
+    Cnipa,
+    Wipo,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum FamilyRelationship {
+    ProvisionalBenefit, // non-provisional claiming a provisional
+    Continuation,
+    Divisional,
+    ContinuationInPart,
+    Pct,
+    ForeignCounterpart,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct StatusChange {
+    pub from: PatentStatus,
+    pub to: PatentStatus,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct DocGenEvent {
+    pub doc_id: u64,
+    pub doc_type: DocType,
+    pub status: DocGenStatus,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct AlertEvent {
+    pub alert_id: u64,
+    pub alert_type: String,
+    pub severity: AlertSeverity,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct StageUpdate {
+    pub stage: Stage,
+    pub percent: u8,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum ApplicationEventPayload {
+    Submitted(String),           // title
+    StatusChanged(StatusChange),
+    PriorArtAdded(u64),          // result_id
+    DocumentGenerated(DocGenEvent),
+    BlockchainRecorded(u64),     // record_id
+    AlertRaised(AlertEvent),
+    StageUpdated(StageUpdate),
+    OfficeActionMailed(u64),     // action_id
+    OfficeActionResponded(u64),  // action_id
+    GrantRecorded(String),       // patent_number
+    MaintenanceFeePaid(u64),     // fee_id
+    PatentLapsed(u64),           // fee_id of the unpaid fee
+    FamilyLinked(u64),           // link_id
+    InventorNamed(Identity),
+    InventorConfirmed(Identity),
+    InventorshipDisputed(Identity),
+    DocumentVersionSaved(u64),   // version_id
+    DocumentVersionFiled(u64),   // version_id
+    IdsGenerated(u64),           // ids_id
+    Imported(u64),               // report_id
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum PipelineMetricKind {
+    MedianDaysInStage,
+    MedianIdeaToFilingDays,
+    MedianFilingToGrantDays,
+    StageConversionRate,
+    StalledCount,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum RollupResolution {
+    Hourly,
+    Daily,
+}
+
 #[derive(SpacetimeType, Clone, Debug, PartialEq)]
 pub enum Stage {
     Idea,
@@ -128,6 +332,17 @@ pub struct PriorArtResult {
     found_at: Timestamp,
 }
 
+#[table(name = prior_art_review, public)]
+#[derive(Clone)]
+pub struct PriorArtReview {
+    #[primary_key]
+    result_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    reviewed_by: Identity,
+    reviewed_at: Timestamp,
+}
+
 #[table(name = document_generation, public)]
 #[derive(Clone)]
 pub struct DocumentGeneration {
@@ -142,672 +357,6306 @@ pub struct DocumentGeneration {
     updated_at: Timestamp,
 }
 
-#[table(name = blockchain_record, public)]
+#[table(name = office_action, public)]
 #[derive(Clone)]
-pub struct BlockchainRecord {
+pub struct OfficeAction {
     #[primary_key]
     #[auto_inc]
-    record_id: u64,
+    action_id: u64,
     #[index(btree)]
     application_id: u64,
-    tx_hash: String,
-    network: String,
-    recorded_at: Timestamp,
+    action_type: OfficeActionType,
+    mailed_at: Timestamp,
+    shortened_deadline: Timestamp, // shortened statutory period, before extensions
+    statutory_deadline: Timestamp, // hard bar; no response is possible after this
+    extension_months: u8,
+    extension_fee_cents: u64,
+    response_filed_at: Option<Timestamp>,
+    recorded_by: Identity,
+    created_at: Timestamp,
 }
 
-#[table(name = collab_session, public)]
+#[table(name = filing_detail, public)]
 #[derive(Clone)]
-pub struct CollaborationSession {
+pub struct FilingDetail {
+    #[primary_key]
+    application_id: u64,
+    filing_type: FilingType,
+    filing_date: Timestamp,
+    priority_date: Option<Timestamp>, // earliest claimed priority, if any
+    updated_at: Timestamp,
+}
+
+#[table(name = deadline, public)]
+#[derive(Clone)]
+pub struct Deadline {
     #[primary_key]
     #[auto_inc]
-    session_id: u64,
-    title: String,
+    deadline_id: u64,
     #[index(btree)]
-    created_by: Identity,
-    status: CollabStatus,
-    started_at: Timestamp,
-    ended_at: Timestamp,
-    ended: bool,
+    application_id: u64,
+    kind: DeadlineKind,
+    source_id: u64, // e.g., action_id for office action deadlines, 0 otherwise
+    label: String,  // distinguishes deadlines of the same kind, e.g., "30 months", "7.5 years"
+    due_at: Timestamp,
+    completed: bool,
+    created_at: Timestamp,
+    updated_at: Timestamp,
 }
 
-#[table(name = collab_participant, public)]
+#[table(name = deadline_reminder, public)]
 #[derive(Clone)]
-pub struct SessionParticipant {
+pub struct DeadlineReminder {
     #[primary_key]
     #[auto_inc]
-    row_id: u64,
+    reminder_id: u64,
     #[index(btree)]
-    session_id: u64,
+    deadline_id: u64,
     #[index(btree)]
-    participant: Identity,
-    joined_at: Timestamp,
-    active: bool,
+    recipient: Identity,
+    lead_days: u32, // 0 means the deadline was missed
+    due_at: Timestamp,
+    created_at: Timestamp,
+    acknowledged: bool,
 }
 
-#[table(name = portfolio_entry, public)]
+#[table(name = reminder_lead_time, public)]
 #[derive(Clone)]
-pub struct PatentPortfolioEntry {
+pub struct ReminderLeadTime {
+    #[primary_key]
+    lead_days: u32,
+}
+
+#[table(name = grant_record, public)]
+#[derive(Clone)]
+pub struct GrantRecord {
+    #[primary_key]
+    application_id: u64,
+    #[unique]
+    patent_number: String,
+    grant_date: Timestamp,
+    expiry_date: Timestamp,
+    lapsed: bool,
+    lapsed_at: Option<Timestamp>,
+    recorded_at: Timestamp,
+}
+
+#[table(name = maintenance_fee, public)]
+#[derive(Clone)]
+pub struct MaintenanceFee {
     #[primary_key]
     #[auto_inc]
-    entry_id: u64,
-    #[index(btree)]
-    owner: Identity,
+    fee_id: u64,
     #[index(btree)]
     application_id: u64,
-    role: PortfolioRole,
-    added_at: Timestamp,
+    label: String, // "3.5 years", "7.5 years", "11.5 years"
+    window_opens_at: Timestamp,
+    due_at: Timestamp,        // last day to pay without surcharge
+    grace_ends_at: Timestamp, // last day to pay with surcharge; the patent lapses after this
+    paid_at: Option<Timestamp>,
+    fee_cents: u64,
+    surcharge_cents: u64,
 }
 
-#[table(name = inventor_connection, public)]
+#[table(name = classification, public)]
 #[derive(Clone)]
-pub struct InventorConnection {
+pub struct Classification {
     #[primary_key]
     #[auto_inc]
-    connection_id: u64,
-    #[index(btree)]
-    a: Identity,
+    classification_id: u64,
     #[index(btree)]
-    b: Identity,
-    status: ConnectionStatus,
+    application_id: u64,
+    scheme: ClassificationScheme,
+    code: String, // e.g., "G06F" or "G06F 16/00"
+    primary: bool,
+    source: ClassificationSource,
+    score: f32, // keyword match strength for suggestions, 0 otherwise
     created_at: Timestamp,
-    updated_at: Timestamp,
 }
 
-#[table(name = infringement_alert, public)]
+// Named inventors of an application, who need not be its owner
+#[table(name = application_inventor, public)]
 #[derive(Clone)]
-pub struct InfringementAlert {
+pub struct ApplicationInventor {
     #[primary_key]
     #[auto_inc]
-    alert_id: u64,
+    row_id: u64,
     #[index(btree)]
     application_id: u64,
-    alert_type: String,
-    severity: AlertSeverity,
-    description: String,
-    detected_at: Timestamp,
-    resolved: bool,
+    #[index(btree)]
+    inventor: Identity,
+    position: u32, // order of naming, starting at 1
+    contribution: String,
+    contribution_percent: Option<u8>,
+    declaration_status: DeclarationStatus,
+    dispute_reason: String,
+    updated_at: Timestamp,
 }
 
-#[table(name = market_trend_snapshot, public)]
+// One row per office an invention is filed with
+#[table(name = jurisdiction_filing, public)]
 #[derive(Clone)]
-pub struct MarketTrendSnapshot {
+pub struct JurisdictionFiling {
     #[primary_key]
     #[auto_inc]
-    snapshot_id: u64,
+    filing_id: u64,
     #[index(btree)]
-    segment: String, // e.g., "global", "AI", "US", "IPC:G06F"
-    metric: TrendMetric,
-    value: f64,
-    window: String, // e.g., "all_time", "24h", "7d", "30d"
-    computed_at: Timestamp,
+    application_id: u64,
+    office: PatentOffice,
+    application_number: String,
+    filing_date: Timestamp,
+    status: PatentStatus,
+    created_at: Timestamp,
+    updated_at: Timestamp,
 }
 
-#[table(name = stage_progress, public)]
+// `child` claims priority from `parent`
+#[table(name = family_link, public)]
 #[derive(Clone)]
-pub struct StageProgress {
+pub struct FamilyLink {
     #[primary_key]
     #[auto_inc]
-    progress_id: u64,
+    link_id: u64,
+    #[index(btree)]
+    parent_application_id: u64,
     #[index(btree)]
+    child_application_id: u64,
+    relationship: FamilyRelationship,
+    priority_date: Timestamp,
+    created_by: Identity,
+    created_at: Timestamp,
+}
+
+// Denormalized family tree for the portfolio dashboard, one row per family member
+#[table(name = family_node, public)]
+#[derive(Clone)]
+pub struct FamilyNode {
+    #[primary_key]
     application_id: u64,
-    stage: Stage,
-    percent: u8,
+    #[index(btree)]
+    root_application_id: u64,
+    parent_application_id: Option<u64>, // the parent with the earliest priority date
+    relationship: Option<FamilyRelationship>,
+    depth: u32,
+    earliest_priority_date: Option<Timestamp>,
     updated_at: Timestamp,
 }
 
-#[table(name = monitoring_schedule, public, scheduled(monitor_tick))]
+// Generated document content, one row per saved revision; list an application's
+// versions by subscribing on application_id
+#[table(name = document_version, public)]
 #[derive(Clone)]
-pub struct MonitoringSchedule {
+pub struct DocumentVersion {
     #[primary_key]
     #[auto_inc]
-    scheduled_id: u64,
-    scheduled_at: ScheduleAt,
+    version_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    doc_type: DocType,
+    version_number: u32,
+    content: String,
+    content_hash: String, // hex SHA-256 of content
+    generator: String,    // generator/model identifier, empty for hand edits
+    author: Identity,
+    parent_version_id: Option<u64>,
+    filed: bool, // the version that was filed with the office
+    created_at: Timestamp,
 }
 
-#[table(name = analytics_schedule, public, scheduled(analytics_tick))]
+// Word-level comparison of two versions of the same document
+#[table(name = document_diff, public)]
 #[derive(Clone)]
-pub struct AnalyticsSchedule {
+pub struct DocumentDiff {
     #[primary_key]
     #[auto_inc]
-    scheduled_id: u64,
-    scheduled_at: ScheduleAt,
+    diff_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    doc_type: DocType,
+    from_version_id: u64,
+    to_version_id: u64,
+    segments: Vec<DiffSegment>,
+    inserted_words: u32,
+    deleted_words: u32,
+    redline: String, // HTML with <ins>/<del> markup
+    created_by: Identity,
+    created_at: Timestamp,
 }
 
-// ---------- Lifecycle Reducers ----------
+// Document content and redlines are visible to the application owner and to co-owners the owner
+// granted; self-assigned Licensee/Viewer portfolio entries do not unlock them
+#[client_visibility_filter]
+const DOCUMENT_VERSION_OWNER: Filter = Filter::Sql(
+    "SELECT document_version.* FROM document_version JOIN patent_application ON document_version.application_id = patent_application.application_id WHERE patent_application.owner = :sender",
+);
 
-#[reducer(init)]
-pub fn init(ctx: &ReducerContext) -> Result<(), String> {
-    spacetimedb::log::info!("Initializing Patent Ecosystem module...");
+#[client_visibility_filter]
+const DOCUMENT_VERSION_CO_OWNER: Filter = Filter::Sql(
+    "SELECT document_version.* FROM document_version JOIN co_owner_grant ON document_version.application_id = co_owner_grant.application_id WHERE co_owner_grant.holder = :sender",
+);
 
-    if ctx.db.monitoring_schedule().count() == 0 {
-        let schedule = MonitoringSchedule {
-            scheduled_id: 0,
-            scheduled_at: ScheduleAt::Interval(Duration::from_secs(60).into()),
-        };
-        match ctx.db.monitoring_schedule().try_insert(schedule) {
-            Ok(row) => spacetimedb::log::info!("Monitoring scheduled with ID: {}", row.scheduled_id),
-            Err(e) => spacetimedb::log::error!("Failed to schedule monitoring: {}", e),
-        }
-    }
+#[client_visibility_filter]
+const DOCUMENT_DIFF_OWNER: Filter = Filter::Sql(
+    "SELECT document_diff.* FROM document_diff JOIN patent_application ON document_diff.application_id = patent_application.application_id WHERE patent_application.owner = :sender",
+);
 
-    if ctx.db.analytics_schedule().count() == 0 {
-        let schedule = AnalyticsSchedule {
-            scheduled_id: 0,
-            scheduled_at: ScheduleAt::Interval(Duration::from_secs(300).into()),
-        };
-        match ctx.db.analytics_schedule().try_insert(schedule) {
-            Ok(row) => spacetimedb::log::info!("Analytics scheduled with ID: {}", row.scheduled_id),
-            Err(e) => spacetimedb::log::error!("Failed to schedule analytics: {}", e),
-        }
-    }
+#[client_visibility_filter]
+const DOCUMENT_DIFF_CO_OWNER: Filter = Filter::Sql(
+    "SELECT document_diff.* FROM document_diff JOIN co_owner_grant ON document_diff.application_id = co_owner_grant.application_id WHERE co_owner_grant.holder = :sender",
+);
 
-    Ok(())
+// External AI workers allowed to pull document generation jobs
+#[table(name = doc_worker, public)]
+#[derive(Clone)]
+pub struct DocWorker {
+    #[primary_key]
+    identity: Identity,
+    added_at: Timestamp,
 }
 
-#[reducer(client_connected)]
-pub fn identity_connected(ctx: &ReducerContext) {
-    spacetimedb::log::info!("Client connected: {}", ctx.sender);
+#[table(name = doc_job, public)]
+#[derive(Clone)]
+pub struct DocJob {
+    #[primary_key]
+    #[auto_inc]
+    job_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    doc_type: DocType,
+    priority: u32, // higher runs first
+    status: DocJobStatus,
+    attempts: u32,
+    max_attempts: u32,
+    #[index(btree)]
+    leased_by: Option<Identity>,
+    lease_expires_at: Option<Timestamp>,
+    available_at: Timestamp, // not claimable before this, for retry backoff
+    last_error: String,
+    result_version_id: Option<u64>,
+    created_by: Identity,
+    created_at: Timestamp,
+    updated_at: Timestamp,
 }
 
-#[reducer(client_disconnected)]
-pub fn identity_disconnected(ctx: &ReducerContext) {
-    spacetimedb::log::info!("Client disconnected: {}", ctx.sender);
+// One row per generation run of a document, for latency and failure analytics
+#[table(name = doc_gen_attempt, public)]
+#[derive(Clone)]
+pub struct DocGenAttempt {
+    #[primary_key]
+    #[auto_inc]
+    attempt_id: u64,
+    #[index(btree)]
+    doc_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    doc_type: DocType,
+    started_at: Timestamp,
+    finished_at: Option<Timestamp>,
+    duration_ms: Option<u64>,
+    outcome: Option<DocGenStatus>, // Completed or Failed once finished
 }
 
-// ---------- Core Reducers ----------
+// Latest generation latency and failure rate per DocType, recomputed by analytics_tick
+#[table(name = doc_gen_metric, public)]
+#[derive(Clone)]
+pub struct DocGenMetric {
+    #[primary_key]
+    #[auto_inc]
+    metric_id: u64,
+    doc_type: DocType,
+    finished_attempts: u64,
+    failed_attempts: u64,
+    failure_rate: f64,
+    avg_duration_ms: f64,
+    median_duration_ms: f64,
+    computed_at: Timestamp,
+}
 
-#[reducer]
-pub fn register_inventor(
-    ctx: &ReducerContext,
-    name: String,
-    email: String,
-    affiliation: String,
-    skills: String,
-    bio: String,
-) -> Result<(), String> {
-    let now = ctx.timestamp;
-    if let Some(mut inv) = ctx.db.inventor().identity().find(&ctx.sender) {
-        inv.name = name;
-        inv.email = email;
-        inv.affiliation = affiliation;
+// One row per document an application needs before it can be filed
+#[table(name = filing_readiness, public)]
+#[derive(Clone)]
+pub struct FilingReadiness {
+    #[primary_key]
+    #[auto_inc]
+    row_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    doc_type: DocType,
+    exists: bool,    // generation started or a version was saved
+    completed: bool, // generation Completed
+    updated_at: Timestamp,
+}
+
+// How a prior art result is cited in an Information Disclosure Statement
+#[table(name = prior_art_citation, public)]
+#[derive(Clone)]
+pub struct PriorArtCitation {
+    #[primary_key]
+    result_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    kind: ReferenceKind,
+    publication_number: String, // empty for non-patent literature
+    publication_date: Option<Timestamp>,
+    dedupe_key: String,
+    cited_in_ids_id: Option<u64>,
+}
+
+#[table(name = ids_filing, public)]
+#[derive(Clone)]
+pub struct IdsFiling {
+    #[primary_key]
+    #[auto_inc]
+    ids_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    sequence: u32, // 1 for the first IDS of the application
+    version_id: u64,
+    reference_count: u32,
+    created_by: Identity,
+    created_at: Timestamp,
+}
+
+// Outcome of one import_portfolio call. Either every row was imported or none was.
+#[table(name = import_report, public)]
+#[derive(Clone)]
+pub struct ImportReport {
+    #[primary_key]
+    #[auto_inc]
+    report_id: u64,
+    #[index(btree)]
+    owner: Identity,
+    format: ImportFormat,
+    total_rows: u32,
+    committed: bool,
+    application_ids: Vec<u64>,
+    errors: Vec<ImportRowError>,
+    created_at: Timestamp,
+}
+
+#[client_visibility_filter]
+const IMPORT_REPORT_OWNER: Filter = Filter::Sql("SELECT * FROM import_report WHERE owner = :sender");
+
+// Generated files the client can download; only visible to whoever requested them
+#[table(name = export_artifact, public)]
+#[derive(Clone)]
+pub struct ExportArtifact {
+    #[primary_key]
+    #[auto_inc]
+    artifact_id: u64,
+    #[index(btree)]
+    owner: Identity,
+    application_id: Option<u64>,
+    format: ExportFormat,
+    schema_version: String,
+    file_name: String,
+    content_type: String,
+    content: String,
+    content_hash: String, // hex SHA-256 of content
+    created_at: Timestamp,
+}
+
+#[client_visibility_filter]
+const EXPORT_ARTIFACT_OWNER: Filter = Filter::Sql("SELECT * FROM export_artifact WHERE owner = :sender");
+
+#[table(name = blockchain_record, public)]
+#[derive(Clone)]
+pub struct BlockchainRecord {
+    #[primary_key]
+    #[auto_inc]
+    record_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    tx_hash: String,
+    network: String,
+    recorded_at: Timestamp,
+}
+
+// Digest of one application revision; `digest` is the payload to anchor on chain
+#[table(name = notarization, public)]
+#[derive(Clone)]
+pub struct Notarization {
+    #[primary_key]
+    #[auto_inc]
+    notarization_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    scheme: String,        // canonical serialization version, see NOTARIZATION_SCHEME
+    digest: String,        // hex SHA-256 of the canonical serialization
+    version_ids: Vec<u64>, // document versions covered by the digest
+    requested_by: Identity,
+    created_at: Timestamp,
+    record_id: Option<u64>, // blockchain record once anchored
+    #[index(btree)]
+    batch_requested: bool, // owner opted in to Merkle-batched anchoring
+    batch_id: Option<u64>, // set once the digest is a leaf of a batch
+}
+
+// Many notarization digests anchored with one transaction over their Merkle root
+#[table(name = merkle_batch, public)]
+#[derive(Clone)]
+pub struct MerkleBatch {
+    #[primary_key]
+    #[auto_inc]
+    batch_id: u64,
+    root: String, // hex
+    leaves: Vec<MerkleLeaf>,
+    created_at: Timestamp,
+    tx_hash: Option<String>,
+    network: String,
+    anchored_at: Option<Timestamp>,
+}
+
+// Links the per-application blockchain records of an anchored batch to its root
+#[table(name = blockchain_record_batch, public)]
+#[derive(Clone)]
+pub struct BlockchainRecordBatch {
+    #[primary_key]
+    record_id: u64,
+    #[index(btree)]
+    batch_id: u64,
+    notarization_id: u64,
+    merkle_root: String,
+}
+
+#[table(name = collab_session, public)]
+#[derive(Clone)]
+pub struct CollaborationSession {
+    #[primary_key]
+    #[auto_inc]
+    session_id: u64,
+    title: String,
+    #[index(btree)]
+    created_by: Identity,
+    status: CollabStatus,
+    started_at: Timestamp,
+    ended_at: Timestamp,
+    ended: bool,
+}
+
+#[table(name = collab_participant, public)]
+#[derive(Clone)]
+pub struct SessionParticipant {
+    #[primary_key]
+    #[auto_inc]
+    row_id: u64,
+    #[index(btree)]
+    session_id: u64,
+    #[index(btree)]
+    participant: Identity,
+    joined_at: Timestamp,
+    active: bool,
+}
+
+#[table(name = portfolio_entry, public)]
+#[derive(Clone)]
+pub struct PatentPortfolioEntry {
+    #[primary_key]
+    #[auto_inc]
+    entry_id: u64,
+    #[index(btree)]
+    owner: Identity,
+    #[index(btree)]
+    application_id: u64,
+    role: PortfolioRole,
+    added_at: Timestamp,
+}
+
+#[table(name = inventor_connection, public)]
+#[derive(Clone)]
+pub struct InventorConnection {
+    #[primary_key]
+    #[auto_inc]
+    connection_id: u64,
+    #[index(btree)]
+    a: Identity,
+    #[index(btree)]
+    b: Identity,
+    status: ConnectionStatus,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+}
+
+#[table(name = infringement_alert, public)]
+#[derive(Clone)]
+pub struct InfringementAlert {
+    #[primary_key]
+    #[auto_inc]
+    alert_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    alert_type: String,
+    severity: AlertSeverity,
+    description: String,
+    detected_at: Timestamp,
+    resolved: bool,
+}
+
+#[table(name = market_trend_snapshot, public)]
+#[derive(Clone)]
+pub struct MarketTrendSnapshot {
+    #[primary_key]
+    #[auto_inc]
+    snapshot_id: u64,
+    #[index(btree)]
+    segment: String, // e.g., "global", "AI", "US", "IPC:G06F"
+    metric: TrendMetric,
+    value: f64,
+    window: String, // e.g., "all_time", "24h", "7d", "30d"
+    computed_at: Timestamp,
+}
+
+// Downsampled market_trend_snapshot values once raw rows age out
+#[table(name = market_trend_rollup, public)]
+#[derive(Clone)]
+pub struct MarketTrendRollup {
+    #[primary_key]
+    #[auto_inc]
+    rollup_id: u64,
+    #[index(btree)]
+    segment: String,
+    metric: TrendMetric,
+    window: String,
+    resolution: RollupResolution,
+    bucket_start: Timestamp,
+    min: f64,
+    max: f64,
+    avg: f64,
+    last: f64,
+    last_at: Timestamp,
+    sample_count: u64,
+}
+
+// Organizations are created by admins; membership is managed by admins or the organization's managers
+#[table(name = organization, public)]
+#[derive(Clone)]
+pub struct Organization {
+    #[primary_key]
+    name: String,
+    created_by: Identity,
+    created_at: Timestamp,
+}
+
+#[table(name = organization_member, public)]
+#[derive(Clone)]
+pub struct OrganizationMember {
+    #[primary_key]
+    #[auto_inc]
+    row_id: u64,
+    #[index(btree)]
+    organization: String,
+    #[index(btree)]
+    member: Identity,
+    manager: bool, // may add and remove members
+    added_by: Identity,
+    added_at: Timestamp,
+}
+
+// Co-owners granted by the application owner; unlike portfolio entries these are not self-assigned
+#[table(name = co_owner_grant, public)]
+#[derive(Clone)]
+pub struct CoOwnerGrant {
+    #[primary_key]
+    #[auto_inc]
+    row_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    #[index(btree)]
+    holder: Identity,
+    granted_at: Timestamp,
+}
+
+// Per-customer snapshots; each identity only sees the rows addressed to it
+#[table(name = portfolio_trend_snapshot, public)]
+#[derive(Clone)]
+pub struct PortfolioTrendSnapshot {
+    #[primary_key]
+    #[auto_inc]
+    snapshot_id: u64,
+    #[index(btree)]
+    viewer: Identity,
+    scope: String, // "owner" or "org:<organization>"
+    metric: TrendMetric,
+    value: f64,
+    window: String,
+    computed_at: Timestamp,
+}
+
+#[client_visibility_filter]
+const PORTFOLIO_TREND_SNAPSHOT_VIEWER: Filter = Filter::Sql("SELECT * FROM portfolio_trend_snapshot WHERE viewer = :sender");
+
+#[table(name = stage_progress, public)]
+#[derive(Clone)]
+pub struct StageProgress {
+    #[primary_key]
+    #[auto_inc]
+    progress_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    stage: Stage,
+    percent: u8,
+    updated_at: Timestamp,
+}
+
+// Chronological feed of everything that happens to an application
+#[table(name = application_event, public)]
+#[derive(Clone)]
+pub struct ApplicationEvent {
+    #[primary_key]
+    #[auto_inc]
+    event_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    actor: Identity,
+    payload: ApplicationEventPayload,
+    occurred_at: Timestamp,
+}
+
+#[table(name = admin, public)]
+#[derive(Clone)]
+pub struct Admin {
+    #[primary_key]
+    identity: Identity,
+    added_at: Timestamp,
+}
+
+#[table(name = module_config, public)]
+#[derive(Clone)]
+pub struct ModuleConfig {
+    #[primary_key]
+    job: String, // e.g., "monitoring", "analytics"
+    interval_secs: u64,
+    paused: bool,
+    updated_at: Timestamp,
+}
+
+// First time an application entered each stage
+#[table(name = stage_transition, public)]
+#[derive(Clone)]
+pub struct StageTransition {
+    #[primary_key]
+    #[auto_inc]
+    transition_id: u64,
+    #[index(btree)]
+    application_id: u64,
+    stage: Stage,
+    entered_at: Timestamp,
+}
+
+#[table(name = pipeline_metric, public)]
+#[derive(Clone)]
+pub struct PipelineMetric {
+    #[primary_key]
+    #[auto_inc]
+    metric_id: u64,
+    kind: PipelineMetricKind,
+    stage: Option<Stage>, // set for per-stage metrics
+    value: f64,
+    sample_count: u64,
+    computed_at: Timestamp,
+}
+
+#[table(name = monitoring_schedule, public, scheduled(monitor_tick))]
+#[derive(Clone)]
+pub struct MonitoringSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+#[table(name = analytics_schedule, public, scheduled(analytics_tick))]
+#[derive(Clone)]
+pub struct AnalyticsSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+#[table(name = compaction_schedule, public, scheduled(compaction_tick))]
+#[derive(Clone)]
+pub struct CompactionSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+#[table(name = docket_schedule, public, scheduled(docket_tick))]
+#[derive(Clone)]
+pub struct DocketSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+#[table(name = maintenance_schedule, public, scheduled(maintenance_tick))]
+#[derive(Clone)]
+pub struct MaintenanceSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+#[table(name = doc_lease_schedule, public, scheduled(reclaim_doc_job_leases))]
+#[derive(Clone)]
+pub struct DocLeaseSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+#[table(name = merkle_schedule, public, scheduled(merkle_batch_tick))]
+#[derive(Clone)]
+pub struct MerkleSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+// ---------- Scheduler Config ----------
+
+const MONITORING_JOB: &str = "monitoring";
+const ANALYTICS_JOB: &str = "analytics";
+const COMPACTION_JOB: &str = "compaction";
+const DOCKETING_JOB: &str = "docketing";
+const MAINTENANCE_JOB: &str = "maintenance";
+const DOC_LEASE_JOB: &str = "doc_job_leases";
+const MERKLE_JOB: &str = "merkle_batching";
+const SCHEDULED_JOBS: &[&str] =
+    &[MONITORING_JOB, ANALYTICS_JOB, COMPACTION_JOB, DOCKETING_JOB, MAINTENANCE_JOB, DOC_LEASE_JOB, MERKLE_JOB];
+
+// No job needs to run less than once a year; the cap also keeps schedule durations far from
+// the range where they overflow i64 microseconds
+const MAX_JOB_INTERVAL_SECS: u64 = 366 * 86_400;
+
+fn default_interval_secs(job: &str) -> Option<u64> {
+    match job {
+        MONITORING_JOB => Some(60),
+        ANALYTICS_JOB => Some(300),
+        COMPACTION_JOB => Some(3_600),
+        DOCKETING_JOB => Some(3_600),
+        MAINTENANCE_JOB => Some(86_400),
+        DOC_LEASE_JOB => Some(60),
+        MERKLE_JOB => Some(3_600),
+        _ => None,
+    }
+}
+
+fn require_admin(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.admin().identity().find(ctx.sender).is_some() {
+        Ok(())
+    } else {
+        Err("Only an admin can perform this action".into())
+    }
+}
+
+fn require_owner(ctx: &ReducerContext, application_id: u64) -> Result<PatentApplication, String> {
+    match ctx.db.patent_application().application_id().find(application_id) {
+        Some(app) if app.owner == ctx.sender => Ok(app),
+        Some(_) => Err("Only the owner can modify this application".into()),
+        None => Err("Application not found".into()),
+    }
+}
+
+// Returns the config row for a job, inserting the default if it doesn't exist yet
+fn job_config(ctx: &ReducerContext, job: &str) -> Result<ModuleConfig, String> {
+    if let Some(cfg) = ctx.db.module_config().job().find(job.to_string()) {
+        return Ok(cfg);
+    }
+    let interval_secs = default_interval_secs(job).ok_or_else(|| format!("Unknown scheduled job '{}'", job))?;
+    let cfg = ModuleConfig {
+        job: job.to_string(),
+        interval_secs,
+        paused: false,
+        updated_at: ctx.timestamp,
+    };
+    Ok(ctx.db.module_config().insert(cfg))
+}
+
+// Expands to one match arm per scheduled job that replaces the job's ScheduleAt rows; adding a
+// job means adding one `JOB => table, RowType, "Label";` line to the list in sync_schedule
+macro_rules! sync_job_schedules {
+    ($ctx:expr, $job:expr, $cfg:expr, $scheduled_at:expr, { $($name:path => $table:ident, $row:ident, $label:literal;)* }) => {
+        match $job {
+            $(
+                $name => {
+                    let ids: Vec<u64> = $ctx.db.$table().iter().map(|s| s.scheduled_id).collect();
+                    for id in ids {
+                        $ctx.db.$table().scheduled_id().delete(id);
+                    }
+                    if !$cfg.paused {
+                        let row = $ctx.db.$table().insert($row { scheduled_id: 0, scheduled_at: $scheduled_at });
+                        spacetimedb::log::info!(concat!($label, " scheduled with ID: {} every {}s"), row.scheduled_id, $cfg.interval_secs);
+                    }
+                }
+            )*
+            _ => return Err(format!("Unknown scheduled job '{}'", $job)),
+        }
+    };
+}
+
+// Replaces the ScheduleAt rows of a job so they match its config (none while paused)
+fn sync_schedule(ctx: &ReducerContext, job: &str) -> Result<(), String> {
+    let cfg = job_config(ctx, job)?;
+    let scheduled_at = ScheduleAt::Interval(Duration::from_secs(cfg.interval_secs).into());
+    sync_job_schedules!(ctx, job, cfg, scheduled_at, {
+        MONITORING_JOB => monitoring_schedule, MonitoringSchedule, "Monitoring";
+        ANALYTICS_JOB => analytics_schedule, AnalyticsSchedule, "Analytics";
+        COMPACTION_JOB => compaction_schedule, CompactionSchedule, "Compaction";
+        DOCKETING_JOB => docket_schedule, DocketSchedule, "Docketing";
+        MAINTENANCE_JOB => maintenance_schedule, MaintenanceSchedule, "Maintenance";
+        DOC_LEASE_JOB => doc_lease_schedule, DocLeaseSchedule, "Doc job lease reclaim";
+        MERKLE_JOB => merkle_schedule, MerkleSchedule, "Merkle batching";
+    });
+    Ok(())
+}
+
+// ---------- Lifecycle Reducers ----------
+
+#[reducer(init)]
+pub fn init(ctx: &ReducerContext) -> Result<(), String> {
+    spacetimedb::log::info!("Initializing Patent Ecosystem module...");
+
+    // The publishing identity becomes the first admin
+    if ctx.db.admin().identity().find(ctx.sender).is_none() {
+        ctx.db.admin().insert(Admin {
+            identity: ctx.sender,
+            added_at: ctx.timestamp,
+        });
+    }
+
+    seed_schedules(ctx)
+}
+
+// init only runs on the first publish, so a database created by an older version of the module has
+// no admin and lacks the config/schedule rows of newer jobs. Republishing and then calling this
+// fills them in: while the admin table is empty the caller becomes the first admin, afterwards it
+// is admin-only. Jobs keep their stored interval and paused flag.
+#[reducer]
+pub fn bootstrap_module(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.admin().count() == 0 {
+        ctx.db.admin().insert(Admin {
+            identity: ctx.sender,
+            added_at: ctx.timestamp,
+        });
+        spacetimedb::log::info!("Bootstrapped {} as the first admin", ctx.sender);
+    }
+    require_admin(ctx)?;
+    seed_schedules(ctx)
+}
+
+// Creates missing job configs and (re)creates each job's ScheduleAt rows from its config
+fn seed_schedules(ctx: &ReducerContext) -> Result<(), String> {
+    for job in SCHEDULED_JOBS {
+        sync_schedule(ctx, job)?;
+    }
+    Ok(())
+}
+
+#[reducer(client_connected)]
+pub fn identity_connected(ctx: &ReducerContext) {
+    spacetimedb::log::info!("Client connected: {}", ctx.sender);
+}
+
+#[reducer(client_disconnected)]
+pub fn identity_disconnected(ctx: &ReducerContext) {
+    spacetimedb::log::info!("Client disconnected: {}", ctx.sender);
+}
+
+// ---------- Admin Reducers ----------
+
+#[reducer]
+pub fn add_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    if ctx.db.admin().identity().find(identity).is_none() {
+        ctx.db.admin().insert(Admin {
+            identity,
+            added_at: ctx.timestamp,
+        });
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn remove_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    if ctx.db.admin().count() <= 1 {
+        return Err("Cannot remove the last admin".into());
+    }
+    ctx.db.admin().identity().delete(identity);
+    Ok(())
+}
+
+#[reducer]
+pub fn set_job_interval(ctx: &ReducerContext, job: String, interval_secs: u64) -> Result<(), String> {
+    require_admin(ctx)?;
+    if interval_secs == 0 {
+        return Err("Interval must be at least 1 second".into());
+    }
+    if interval_secs > MAX_JOB_INTERVAL_SECS {
+        return Err(format!("Interval must be at most {} seconds", MAX_JOB_INTERVAL_SECS));
+    }
+    let mut cfg = job_config(ctx, &job)?;
+    cfg.interval_secs = interval_secs;
+    cfg.updated_at = ctx.timestamp;
+    ctx.db.module_config().job().update(cfg);
+    sync_schedule(ctx, &job)?;
+    spacetimedb::log::info!("Job {} interval set to {}s", job, interval_secs);
+    Ok(())
+}
+
+#[reducer]
+pub fn pause_job(ctx: &ReducerContext, job: String) -> Result<(), String> {
+    set_job_paused(ctx, job, true)
+}
+
+#[reducer]
+pub fn resume_job(ctx: &ReducerContext, job: String) -> Result<(), String> {
+    set_job_paused(ctx, job, false)
+}
+
+fn set_job_paused(ctx: &ReducerContext, job: String, paused: bool) -> Result<(), String> {
+    require_admin(ctx)?;
+    let mut cfg = job_config(ctx, &job)?;
+    cfg.paused = paused;
+    cfg.updated_at = ctx.timestamp;
+    ctx.db.module_config().job().update(cfg);
+    sync_schedule(ctx, &job)?;
+    spacetimedb::log::info!("Job {} {}", job, if paused { "paused" } else { "resumed" });
+    Ok(())
+}
+
+// ---------- Organizations ----------
+
+fn organization_member(ctx: &ReducerContext, organization: &str, member: Identity) -> Option<OrganizationMember> {
+    ctx.db
+        .organization_member()
+        .member()
+        .filter(member)
+        .find(|m| m.organization == organization)
+}
+
+fn require_org_manager(ctx: &ReducerContext, organization: &str) -> Result<(), String> {
+    if ctx.db.organization().name().find(organization.to_string()).is_none() {
+        return Err("Organization not found".into());
+    }
+    let manager = organization_member(ctx, organization, ctx.sender).is_some_and(|m| m.manager);
+    if manager || require_admin(ctx).is_ok() {
+        Ok(())
+    } else {
+        Err("Only an admin or organization manager can manage members".into())
+    }
+}
+
+#[reducer]
+pub fn create_organization(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    require_admin(ctx)?;
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Organization name is required".into());
+    }
+    if ctx.db.organization().name().find(name.clone()).is_some() {
+        return Err("Organization already exists".into());
+    }
+    ctx.db.organization().insert(Organization { name, created_by: ctx.sender, created_at: ctx.timestamp });
+    Ok(())
+}
+
+#[reducer]
+pub fn add_organization_member(ctx: &ReducerContext, organization: String, member: Identity, manager: bool) -> Result<(), String> {
+    require_org_manager(ctx, &organization)?;
+    match organization_member(ctx, &organization, member) {
+        Some(mut m) => {
+            m.manager = manager;
+            ctx.db.organization_member().row_id().update(m);
+        }
+        None => {
+            ctx.db.organization_member().insert(OrganizationMember {
+                row_id: 0,
+                organization,
+                member,
+                manager,
+                added_by: ctx.sender,
+                added_at: ctx.timestamp,
+            });
+        }
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn remove_organization_member(ctx: &ReducerContext, organization: String, member: Identity) -> Result<(), String> {
+    require_org_manager(ctx, &organization)?;
+    let m = organization_member(ctx, &organization, member).ok_or("Not a member of this organization")?;
+    ctx.db.organization_member().row_id().delete(m.row_id);
+
+    // Former members lose the organization's private snapshots immediately
+    let scope = format!("org:{}", organization);
+    let stale: Vec<u64> = ctx
+        .db
+        .portfolio_trend_snapshot()
+        .viewer()
+        .filter(member)
+        .filter(|s| s.scope == scope)
+        .map(|s| s.snapshot_id)
+        .collect();
+    for id in stale {
+        ctx.db.portfolio_trend_snapshot().snapshot_id().delete(id);
+    }
+    Ok(())
+}
+
+// ---------- Application Events ----------
+
+fn record_event(ctx: &ReducerContext, application_id: u64, payload: ApplicationEventPayload) {
+    ctx.db.application_event().insert(ApplicationEvent {
+        event_id: 0,
+        application_id,
+        actor: ctx.sender,
+        payload,
+        occurred_at: ctx.timestamp,
+    });
+}
+
+// ---------- Date Helpers ----------
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+// Proleptic Gregorian (year, month, day) for a day count since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = i64::from(if month > 2 { month - 3 } else { month + 9 });
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+// Parses a YYYY-MM-DD calendar date as midnight UTC
+fn parse_date(text: &str) -> Result<Timestamp, String> {
+    let invalid = || format!("Invalid date '{}', expected YYYY-MM-DD", text);
+    let parts: Vec<&str> = text.trim().split('-').collect();
+    if parts.len() != 3 || parts[0].len() != 4 || parts[1].len() != 2 || parts[2].len() != 2 {
+        return Err(invalid());
+    }
+    let year: i64 = parts[0].parse().map_err(|_| invalid())?;
+    let month: u32 = parts[1].parse().map_err(|_| invalid())?;
+    let day: u32 = parts[2].parse().map_err(|_| invalid())?;
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return Err(invalid());
+    }
+    Ok(Timestamp::from_micros_since_unix_epoch(days_from_civil(year, month, day) * MICROS_PER_DAY))
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Same day-of-month `months` later; when that day doesn't exist the period ends on the last day of the month
+fn add_months(at: Timestamp, months: u32) -> Timestamp {
+    let micros = at.to_micros_since_unix_epoch();
+    let days = micros.div_euclid(MICROS_PER_DAY);
+    let time_of_day = micros.rem_euclid(MICROS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+    let month_index = (month - 1) as i64 + months as i64;
+    let new_year = year + month_index.div_euclid(12);
+    let new_month = month_index.rem_euclid(12) as u32 + 1;
+    let new_day = day.min(days_in_month(new_year, new_month));
+    Timestamp::from_micros_since_unix_epoch(days_from_civil(new_year, new_month, new_day) * MICROS_PER_DAY + time_of_day)
+}
+
+// ---------- Stage Derivation ----------
+
+// Documents that make up the drafting milestone
+const DRAFTING_DOC_TYPES: [DocType; 4] = [DocType::Spec, DocType::Claims, DocType::Drawings, DocType::Abstract];
+
+// Stages that are only ever derived from status and milestones, never set directly
+fn is_derived_stage(stage: &Stage) -> bool {
+    !matches!(stage, Stage::Idea | Stage::Appeal)
+}
+
+// Upserts StageProgress per (application_id, stage), recording the first entry into the stage
+fn set_stage_percent(ctx: &ReducerContext, application_id: u64, stage: Stage, percent: u8) {
+    let percent = percent.min(100);
+    let existing = ctx
+        .db
+        .stage_progress()
+        .application_id()
+        .filter(application_id)
+        .find(|sp| sp.stage == stage);
+    if let Some(mut row) = existing {
+        if row.percent == percent {
+            return;
+        }
+        row.percent = percent;
+        row.updated_at = ctx.timestamp;
+        ctx.db.stage_progress().progress_id().update(row);
+        record_event(ctx, application_id, ApplicationEventPayload::StageUpdated(StageUpdate { stage, percent }));
+    } else {
+        ctx.db.stage_progress().insert(StageProgress {
+            progress_id: 0,
+            application_id,
+            stage: stage.clone(),
+            percent,
+            updated_at: ctx.timestamp,
+        });
+        ctx.db.stage_transition().insert(StageTransition {
+            transition_id: 0,
+            application_id,
+            stage: stage.clone(),
+            entered_at: ctx.timestamp,
+        });
+        record_event(ctx, application_id, ApplicationEventPayload::StageUpdated(StageUpdate { stage, percent }));
+    }
+
+    // Touch application last_updated if exists
+    if let Some(mut app) = ctx.db.patent_application().application_id().find(application_id) {
+        app.last_updated = ctx.timestamp;
+        ctx.db.patent_application().application_id().update(app);
+    }
+}
+
+// Like set_stage_percent, but never moves a stage backwards
+fn advance_stage(ctx: &ReducerContext, application_id: u64, stage: Stage, percent: u8) {
+    let current = ctx
+        .db
+        .stage_progress()
+        .application_id()
+        .filter(application_id)
+        .find(|sp| sp.stage == stage)
+        .map(|sp| sp.percent);
+    match current {
+        Some(p) if p >= percent => {}
+        _ => set_stage_percent(ctx, application_id, stage, percent),
+    }
+}
+
+// Moves the pipeline forward to match a status change
+fn sync_stage_from_status(ctx: &ReducerContext, application_id: u64, status: &PatentStatus) {
+    match status {
+        PatentStatus::Draft => advance_stage(ctx, application_id, Stage::Drafting, 0),
+        PatentStatus::Submitted => advance_stage(ctx, application_id, Stage::Filing, 100),
+        PatentStatus::Examination => {
+            advance_stage(ctx, application_id, Stage::Filing, 100);
+            advance_stage(ctx, application_id, Stage::Examination, 0);
+        }
+        PatentStatus::Granted => {
+            advance_stage(ctx, application_id, Stage::Filing, 100);
+            advance_stage(ctx, application_id, Stage::Examination, 100);
+            advance_stage(ctx, application_id, Stage::Grant, 100);
+        }
+        PatentStatus::Rejected | PatentStatus::Abandoned => {}
+    }
+}
+
+// Drafting percent is the share of drafting documents that completed generation
+fn recompute_drafting_progress(ctx: &ReducerContext, application_id: u64) {
+    let completed = DRAFTING_DOC_TYPES
+        .iter()
+        .filter(|t| {
+            ctx.db
+                .document_generation()
+                .application_id()
+                .filter(application_id)
+                .any(|d| d.doc_type == **t && d.status == DocGenStatus::Completed)
+        })
+        .count();
+    let percent = (completed * 100 / DRAFTING_DOC_TYPES.len()) as u8;
+    set_stage_percent(ctx, application_id, Stage::Drafting, percent);
+}
+
+// Prior art percent is the share of found results that have been reviewed
+fn recompute_prior_art_progress(ctx: &ReducerContext, application_id: u64) {
+    let total = ctx.db.prior_art_result().application_id().filter(application_id).count();
+    if total == 0 {
+        return;
+    }
+    let reviewed = ctx.db.prior_art_review().application_id().filter(application_id).count();
+    let percent = (reviewed.min(total) * 100 / total) as u8;
+    set_stage_percent(ctx, application_id, Stage::PriorArt, percent);
+}
+
+// Office action percent is the share of actions needing a reply that have been responded to
+fn recompute_office_action_progress(ctx: &ReducerContext, application_id: u64) {
+    let mut total = 0usize;
+    let mut responded = 0usize;
+    for oa in ctx.db.office_action().application_id().filter(application_id) {
+        if oa.action_type == OfficeActionType::NoticeOfAllowance {
+            continue;
+        }
+        total += 1;
+        if oa.response_filed_at.is_some() {
+            responded += 1;
+        }
+    }
+    if let Some(percent) = (responded * 100).checked_div(total) {
+        set_stage_percent(ctx, application_id, Stage::OfficeAction, percent as u8);
+    }
+}
+
+// ---------- Core Reducers ----------
+
+#[reducer]
+pub fn register_inventor(
+    ctx: &ReducerContext,
+    name: String,
+    email: String,
+    affiliation: String,
+    skills: String,
+    bio: String,
+) -> Result<(), String> {
+    let now = ctx.timestamp;
+    if let Some(mut inv) = ctx.db.inventor().identity().find(ctx.sender) {
+        inv.name = name;
+        inv.email = email;
+        inv.affiliation = affiliation;
         inv.skills = skills;
         inv.bio = bio;
         inv.updated_at = now;
         ctx.db.inventor().identity().update(inv);
         Ok(())
     } else {
-        let profile = InventorProfile {
-            identity: ctx.sender,
-            name,
-            email,
-            affiliation,
-            skills,
-            bio,
-            created_at: now,
-            updated_at: now,
-        };
-        ctx.db.inventor().insert(profile);
-        Ok(())
+        let profile = InventorProfile {
+            identity: ctx.sender,
+            name,
+            email,
+            affiliation,
+            skills,
+            bio,
+            created_at: now,
+            updated_at: now,
+        };
+        ctx.db.inventor().insert(profile);
+        Ok(())
+    }
+}
+
+#[reducer]
+pub fn submit_patent(
+    ctx: &ReducerContext,
+    title: String,
+    abstract_text: String,
+    claims_text: String,
+) -> Result<(), String> {
+    let now = ctx.timestamp;
+    let row = PatentApplication {
+        application_id: 0,
+        owner: ctx.sender,
+        title,
+        abstract_text,
+        claims_text,
+        status: PatentStatus::Submitted,
+        created_at: now,
+        last_updated: now,
+    };
+    match ctx.db.patent_application().try_insert(row) {
+        Ok(inserted) => {
+            record_event(ctx, inserted.application_id, ApplicationEventPayload::Submitted(inserted.title.clone()));
+            sync_stage_from_status(ctx, inserted.application_id, &inserted.status);
+            refresh_filing_readiness(ctx, inserted.application_id);
+            spacetimedb::log::info!("Patent submitted by {} app_id={}", inserted.owner, inserted.application_id);
+            Ok(())
+        }
+        Err(e) => Err(format!("Failed to submit patent: {}", e)),
+    }
+}
+
+#[reducer]
+pub fn update_patent_status(
+    ctx: &ReducerContext,
+    application_id: u64,
+    new_status: PatentStatus,
+) -> Result<(), String> {
+    if let Some(mut app) = ctx.db.patent_application().application_id().find(application_id) {
+        if app.owner != ctx.sender {
+            return Err("Only the owner can update patent status".into());
+        }
+        if matches!(new_status, PatentStatus::Submitted | PatentStatus::Examination | PatentStatus::Granted) {
+            require_inventors_confirmed(ctx, application_id)?;
+        }
+        let old_status = app.status.clone();
+        app.status = new_status.clone();
+        app.last_updated = ctx.timestamp;
+        let status_to_log = format!("{:?}", new_status);
+        ctx.db.patent_application().application_id().update(app);
+        if old_status != new_status {
+            let change = StatusChange { from: old_status, to: new_status.clone() };
+            record_event(ctx, application_id, ApplicationEventPayload::StatusChanged(change));
+        }
+        sync_stage_from_status(ctx, application_id, &new_status);
+        recompute_deadlines(ctx, application_id);
+        spacetimedb::log::info!("Updated application {} status to {}", application_id, status_to_log);
+        Ok(())
+    } else {
+        Err("Application not found".into())
+    }
+}
+
+#[reducer]
+pub fn add_prior_art_result(
+    ctx: &ReducerContext,
+    application_id: u64,
+    source: String,
+    url: String,
+    summary: String,
+    relevance_score: f32,
+) -> Result<(), String> {
+    let row = PriorArtResult {
+        result_id: 0,
+        application_id,
+        source,
+        url,
+        summary,
+        relevance_score,
+        found_at: ctx.timestamp,
+    };
+    match ctx.db.prior_art_result().try_insert(row) {
+        Ok(inserted) => {
+            record_event(ctx, inserted.application_id, ApplicationEventPayload::PriorArtAdded(inserted.result_id));
+            recompute_prior_art_progress(ctx, inserted.application_id);
+            spacetimedb::log::info!("Prior art result recorded for app_id={} result_id={}", inserted.application_id, inserted.result_id);
+            Ok(())
+        }
+        Err(e) => Err(format!("Failed to add prior art: {}", e)),
+    }
+}
+
+#[reducer]
+pub fn review_prior_art(ctx: &ReducerContext, result_id: u64) -> Result<(), String> {
+    let result = ctx
+        .db
+        .prior_art_result()
+        .result_id()
+        .find(result_id)
+        .ok_or("Prior art result not found")?;
+    require_owner(ctx, result.application_id)?;
+    if ctx.db.prior_art_review().result_id().find(result_id).is_none() {
+        ctx.db.prior_art_review().insert(PriorArtReview {
+            result_id,
+            application_id: result.application_id,
+            reviewed_by: ctx.sender,
+            reviewed_at: ctx.timestamp,
+        });
+        recompute_prior_art_progress(ctx, result.application_id);
+    }
+    Ok(())
+}
+
+// Manual status updates are limited to the application owner and registered document workers;
+// queued generation goes through the doc job reducers
+#[reducer]
+pub fn upsert_document_generation(
+    ctx: &ReducerContext,
+    application_id: u64,
+    doc_type: DocType,
+    status: DocGenStatus,
+    error_message: String,
+) -> Result<(), String> {
+    if require_doc_worker(ctx).is_err() {
+        require_owner(ctx, application_id)?;
+    }
+    let from = ctx
+        .db
+        .document_generation()
+        .application_id()
+        .filter(application_id)
+        .find(|d| d.doc_type == doc_type)
+        .map(|d| d.status)
+        .unwrap_or(DocGenStatus::NotStarted);
+    let job_active = doc_job_active(ctx, application_id, &doc_type);
+    if !manual_doc_gen_transition_allowed(&from, &status, job_active) {
+        if job_active {
+            return Err(format!("A {:?} job is queued or running; wait for it to finish", doc_type));
+        }
+        return Err(format!("Invalid document generation transition {:?} -> {:?}", from, status));
+    }
+    set_document_generation(ctx, application_id, doc_type, status, error_message)
+}
+
+fn doc_job_active(ctx: &ReducerContext, application_id: u64, doc_type: &DocType) -> bool {
+    ctx.db
+        .doc_job()
+        .application_id()
+        .filter(application_id)
+        .any(|j| j.doc_type == *doc_type && matches!(j.status, DocJobStatus::Queued | DocJobStatus::Leased))
+}
+
+// Generation runs NotStarted -> InProgress -> Completed/Failed, Failed -> InProgress on retry and
+// Completed -> InProgress on regeneration. InProgress -> InProgress is accepted so a requeued job
+// can be leased again.
+fn doc_gen_transition_allowed(from: &DocGenStatus, to: &DocGenStatus) -> bool {
+    matches!(
+        (from, to),
+        (DocGenStatus::NotStarted, DocGenStatus::InProgress)
+            | (DocGenStatus::InProgress, DocGenStatus::InProgress)
+            | (DocGenStatus::InProgress, DocGenStatus::Completed)
+            | (DocGenStatus::InProgress, DocGenStatus::Failed)
+            | (DocGenStatus::Failed, DocGenStatus::InProgress)
+            | (DocGenStatus::Completed, DocGenStatus::InProgress) // explicit regeneration
+    )
+}
+
+// Manual updates follow the same transitions, except that while a job is queued or leased the
+// status belongs to the job pipeline and no manual change is accepted
+fn manual_doc_gen_transition_allowed(from: &DocGenStatus, to: &DocGenStatus, job_active: bool) -> bool {
+    !job_active && ((from == to && *to == DocGenStatus::NotStarted) || doc_gen_transition_allowed(from, to))
+}
+
+fn set_document_generation(
+    ctx: &ReducerContext,
+    application_id: u64,
+    doc_type: DocType,
+    status: DocGenStatus,
+    error_message: String,
+) -> Result<(), String> {
+    // Find existing record for (application_id, doc_type)
+    let existing = ctx
+        .db
+        .document_generation()
+        .application_id()
+        .filter(application_id)
+        .find(|d| d.doc_type == doc_type);
+    let from = existing.as_ref().map(|d| d.status.clone()).unwrap_or(DocGenStatus::NotStarted);
+    let creating_untouched = existing.is_none() && status == DocGenStatus::NotStarted;
+    if !creating_untouched && !doc_gen_transition_allowed(&from, &status) {
+        return Err(format!("Invalid document generation transition {:?} -> {:?}", from, status));
+    }
+
+    // Failures must say why; any other status clears the previous error
+    let error_message = if status == DocGenStatus::Failed {
+        let message = error_message.trim().to_string();
+        if message.is_empty() {
+            return Err("An error message is required when generation fails".into());
+        }
+        message
+    } else {
+        String::new()
+    };
+
+    let log_status = format!("{:?}", status);
+    let log_type = format!("{:?}", doc_type);
+    let doc_id = if let Some(mut doc) = existing {
+        let doc_id = doc.doc_id;
+        doc.status = status.clone();
+        doc.error_message = error_message.clone();
+        doc.updated_at = ctx.timestamp;
+        ctx.db.document_generation().doc_id().update(doc);
+        spacetimedb::log::info!(
+            "Updated doc generation app_id={} type={} status={}",
+            application_id,
+            log_type,
+            log_status
+        );
+        doc_id
+    } else {
+        let doc = DocumentGeneration {
+            doc_id: 0,
+            application_id,
+            doc_type: doc_type.clone(),
+            status: status.clone(),
+            error_message: error_message.clone(),
+            updated_at: ctx.timestamp,
+        };
+        let inserted = ctx.db.document_generation().insert(doc);
+        spacetimedb::log::info!(
+            "Inserted doc generation app_id={} type={} status={}",
+            application_id,
+            log_type,
+            log_status
+        );
+        inserted.doc_id
+    };
+
+    track_doc_gen_attempt(ctx, doc_id, application_id, &doc_type, &from, &status);
+
+    // Only finished generations show up in the application timeline
+    if status == DocGenStatus::Completed || status == DocGenStatus::Failed {
+        let event = DocGenEvent { doc_id, doc_type, status };
+        record_event(ctx, application_id, ApplicationEventPayload::DocumentGenerated(event));
+    }
+    recompute_drafting_progress(ctx, application_id);
+    refresh_filing_readiness(ctx, application_id);
+    Ok(())
+}
+
+// Opens an attempt when generation starts and closes it with its duration when it finishes
+fn track_doc_gen_attempt(
+    ctx: &ReducerContext,
+    doc_id: u64,
+    application_id: u64,
+    doc_type: &DocType,
+    from: &DocGenStatus,
+    to: &DocGenStatus,
+) {
+    let open = ctx.db.doc_gen_attempt().doc_id().filter(doc_id).find(|a| a.finished_at.is_none());
+    match (from, to) {
+        // Re-claiming after a retry starts a new attempt; a repeated InProgress within one attempt does not
+        (_, DocGenStatus::InProgress) if open.is_some() => {}
+        (_, DocGenStatus::InProgress) => {
+            ctx.db.doc_gen_attempt().insert(DocGenAttempt {
+                attempt_id: 0,
+                doc_id,
+                application_id,
+                doc_type: doc_type.clone(),
+                started_at: ctx.timestamp,
+                finished_at: None,
+                duration_ms: None,
+                outcome: None,
+            });
+        }
+        (_, DocGenStatus::Completed | DocGenStatus::Failed) => close_doc_gen_attempt(ctx, doc_id, to),
+        _ => {}
+    }
+}
+
+fn close_doc_gen_attempt(ctx: &ReducerContext, doc_id: u64, outcome: &DocGenStatus) {
+    let open = ctx.db.doc_gen_attempt().doc_id().filter(doc_id).find(|a| a.finished_at.is_none());
+    if let Some(mut attempt) = open {
+        let elapsed = ctx.timestamp.to_micros_since_unix_epoch() - attempt.started_at.to_micros_since_unix_epoch();
+        attempt.finished_at = Some(ctx.timestamp);
+        attempt.duration_ms = Some((elapsed.max(0) / 1_000) as u64);
+        attempt.outcome = Some(outcome.clone());
+        ctx.db.doc_gen_attempt().attempt_id().update(attempt);
+    }
+}
+
+// Blockchain records are only created by anchoring a notarization (anchor_notarization) or a Merkle
+// batch of them (anchor_merkle_batch), so every tx_hash is tied to a digest of the application
+fn insert_blockchain_record(ctx: &ReducerContext, application_id: u64, tx_hash: String, network: String) -> BlockchainRecord {
+    let rec = BlockchainRecord {
+        record_id: 0,
+        application_id,
+        tx_hash,
+        network,
+        recorded_at: ctx.timestamp,
+    };
+    let inserted = ctx.db.blockchain_record().insert(rec);
+    record_event(ctx, application_id, ApplicationEventPayload::BlockchainRecorded(inserted.record_id));
+    inserted
+}
+
+#[reducer]
+pub fn start_collab_session(ctx: &ReducerContext, title: String) -> Result<(), String> {
+    let now = ctx.timestamp;
+    let session = CollaborationSession {
+        session_id: 0,
+        title: title.clone(),
+        created_by: ctx.sender,
+        status: CollabStatus::Active,
+        started_at: now,
+        ended_at: now,
+        ended: false,
+    };
+    let inserted = ctx.db.collab_session().insert(session);
+    let sid = inserted.session_id;
+    let participant = SessionParticipant {
+        row_id: 0,
+        session_id: sid,
+        participant: ctx.sender,
+        joined_at: now,
+        active: true,
+    };
+    ctx.db.collab_participant().insert(participant);
+    spacetimedb::log::info!("Collaboration session started id={} title={}", sid, title);
+    Ok(())
+}
+
+#[reducer]
+pub fn join_collab_session(ctx: &ReducerContext, session_id: u64) -> Result<(), String> {
+    if let Some(sess) = ctx.db.collab_session().session_id().find(session_id) {
+        if sess.status != CollabStatus::Active {
+            return Err("Session is not active".into());
+        }
+        // Check if already participant
+        for p in ctx.db.collab_participant().iter() {
+            if p.session_id == session_id && p.participant == ctx.sender && p.active {
+                return Ok(());
+            }
+        }
+        let row = SessionParticipant {
+            row_id: 0,
+            session_id,
+            participant: ctx.sender,
+            joined_at: ctx.timestamp,
+            active: true,
+        };
+        ctx.db.collab_participant().insert(row);
+        Ok(())
+    } else {
+        Err("Session not found".into())
+    }
+}
+
+#[reducer]
+pub fn leave_collab_session(ctx: &ReducerContext, session_id: u64) -> Result<(), String> {
+    // Mark participant inactive
+    let mut to_update: Vec<u64> = Vec::new();
+    for p in ctx.db.collab_participant().iter() {
+        if p.session_id == session_id && p.participant == ctx.sender && p.active {
+            to_update.push(p.row_id);
+        }
+    }
+    for id in to_update {
+        if let Some(mut row) = ctx.db.collab_participant().row_id().find(id) {
+            row.active = false;
+            ctx.db.collab_participant().row_id().update(row);
+        }
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn close_collab_session(ctx: &ReducerContext, session_id: u64) -> Result<(), String> {
+    if let Some(mut sess) = ctx.db.collab_session().session_id().find(session_id) {
+        if sess.created_by != ctx.sender {
+            return Err("Only the creator can close the session".into());
+        }
+        sess.status = CollabStatus::Closed;
+        sess.ended = true;
+        sess.ended_at = ctx.timestamp;
+        ctx.db.collab_session().session_id().update(sess);
+        Ok(())
+    } else {
+        Err("Session not found".into())
+    }
+}
+
+#[reducer]
+pub fn add_to_portfolio(
+    ctx: &ReducerContext,
+    application_id: u64,
+    role: PortfolioRole,
+) -> Result<(), String> {
+    // Ownership roles must be backed by the application itself or a co-owner grant
+    let app = ctx.db.patent_application().application_id().find(application_id).ok_or("Application not found")?;
+    match role {
+        PortfolioRole::Owner if app.owner != ctx.sender => return Err("Only the application owner can hold the Owner role".into()),
+        PortfolioRole::CoOwner if !is_co_owner(ctx, application_id, ctx.sender) => {
+            return Err("The application owner must grant co-ownership first".into())
+        }
+        _ => {}
+    }
+    // Avoid duplicates (owner+application_id+role)
+    for e in ctx.db.portfolio_entry().iter() {
+        if e.owner == ctx.sender && e.application_id == application_id && e.role == role {
+            return Ok(());
+        }
+    }
+    let row = PatentPortfolioEntry {
+        entry_id: 0,
+        owner: ctx.sender,
+        application_id,
+        role: role.clone(),
+        added_at: ctx.timestamp,
+    };
+    ctx.db.portfolio_entry().insert(row);
+    Ok(())
+}
+
+fn is_co_owner(ctx: &ReducerContext, application_id: u64, holder: Identity) -> bool {
+    ctx.db.co_owner_grant().application_id().filter(application_id).any(|c| c.holder == holder)
+}
+
+#[reducer]
+pub fn add_co_owner(ctx: &ReducerContext, application_id: u64, holder: Identity) -> Result<(), String> {
+    let app = require_owner(ctx, application_id)?;
+    if holder == app.owner || is_co_owner(ctx, application_id, holder) {
+        return Ok(());
+    }
+    ctx.db.co_owner_grant().insert(CoOwnerGrant { row_id: 0, application_id, holder, granted_at: ctx.timestamp });
+    Ok(())
+}
+
+#[reducer]
+pub fn remove_co_owner(ctx: &ReducerContext, application_id: u64, holder: Identity) -> Result<(), String> {
+    require_owner(ctx, application_id)?;
+    let rows: Vec<u64> = ctx
+        .db
+        .co_owner_grant()
+        .application_id()
+        .filter(application_id)
+        .filter(|c| c.holder == holder)
+        .map(|c| c.row_id)
+        .collect();
+    for id in rows {
+        ctx.db.co_owner_grant().row_id().delete(id);
+    }
+    let entries: Vec<u64> = ctx
+        .db
+        .portfolio_entry()
+        .owner()
+        .filter(holder)
+        .filter(|e| e.application_id == application_id && e.role == PortfolioRole::CoOwner)
+        .map(|e| e.entry_id)
+        .collect();
+    for id in entries {
+        ctx.db.portfolio_entry().entry_id().delete(id);
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn connect_inventor(ctx: &ReducerContext, target: Identity) -> Result<(), String> {
+    if target == ctx.sender {
+        return Err("Cannot connect to self".into());
+    }
+    // Prevent duplicates in either direction while pending/connected
+    for c in ctx.db.inventor_connection().iter() {
+        let pair = (c.a == ctx.sender && c.b == target) || (c.a == target && c.b == ctx.sender);
+        if pair && (c.status == ConnectionStatus::Pending || c.status == ConnectionStatus::Connected) {
+            return Ok(());
+        }
+    }
+    let row = InventorConnection {
+        connection_id: 0,
+        a: ctx.sender,
+        b: target,
+        status: ConnectionStatus::Pending,
+        created_at: ctx.timestamp,
+        updated_at: ctx.timestamp,
+    };
+    ctx.db.inventor_connection().insert(row);
+    Ok(())
+}
+
+#[reducer]
+pub fn respond_connection(ctx: &ReducerContext, connection_id: u64, accept: bool) -> Result<(), String> {
+    if let Some(mut conn) = ctx.db.inventor_connection().connection_id().find(connection_id) {
+        if conn.b != ctx.sender && conn.a != ctx.sender {
+            return Err("Not authorized to respond to this connection".into());
+        }
+        conn.status = if accept { ConnectionStatus::Connected } else { ConnectionStatus::Rejected };
+        conn.updated_at = ctx.timestamp;
+        let status_log = format!("{:?}", conn.status);
+        ctx.db.inventor_connection().connection_id().update(conn);
+        spacetimedb::log::info!("Connection {} updated to {}", connection_id, status_log);
+        Ok(())
+    } else {
+        Err("Connection not found".into())
+    }
+}
+
+// Manual progress for stages that have no concrete milestones; the rest follow
+// PatentStatus and lifecycle events
+#[reducer]
+pub fn update_stage_progress(
+    ctx: &ReducerContext,
+    application_id: u64,
+    stage: Stage,
+    percent: u8,
+) -> Result<(), String> {
+    require_owner(ctx, application_id)?;
+    if is_derived_stage(&stage) {
+        return Err(format!("Stage {:?} is derived from status and milestones and cannot be set directly", stage));
+    }
+    if percent > 100 {
+        return Err("Percent must be between 0 and 100".into());
+    }
+    set_stage_percent(ctx, application_id, stage, percent);
+    Ok(())
+}
+
+#[reducer]
+pub fn resolve_alert(ctx: &ReducerContext, alert_id: u64) -> Result<(), String> {
+    if let Some(mut alert) = ctx.db.infringement_alert().alert_id().find(alert_id) {
+        alert.resolved = true;
+        ctx.db.infringement_alert().alert_id().update(alert);
+        Ok(())
+    } else {
+        Err("Alert not found".into())
+    }
+}
+
+// ---------- Office Actions ----------
+
+// (shortened, statutory) response periods in months from the mailing date
+fn office_action_periods(action_type: &OfficeActionType) -> (u32, u32) {
+    match action_type {
+        OfficeActionType::NonFinal | OfficeActionType::Final => (3, 6),
+        OfficeActionType::Restriction => (2, 6),
+        // The issue fee period is statutory and cannot be extended
+        OfficeActionType::NoticeOfAllowance => (3, 3),
+    }
+}
+
+// Scales a large entity fee by the small (60%) and micro (80%) entity reductions
+fn entity_fee_cents(large: u64, entity: &EntitySize) -> u64 {
+    match entity {
+        EntitySize::Large => large,
+        EntitySize::Small => large * 40 / 100,
+        EntitySize::Micro => large * 20 / 100,
+    }
+}
+
+// USPTO extension of time fees under 37 CFR 1.17(a), in cents
+fn extension_fee_cents(months: u8, entity: &EntitySize) -> u64 {
+    let large: u64 = match months {
+        0 => 0,
+        1 => 22_000,
+        2 => 64_000,
+        3 => 150_000,
+        4 => 232_000,
+        _ => 316_000,
+    };
+    entity_fee_cents(large, entity)
+}
+
+// Last day a response can be filed given the extensions purchased so far
+fn office_action_response_deadline(oa: &OfficeAction) -> Timestamp {
+    let (shortened, _) = office_action_periods(&oa.action_type);
+    add_months(oa.mailed_at, shortened + oa.extension_months as u32)
+}
+
+#[reducer]
+pub fn record_office_action(
+    ctx: &ReducerContext,
+    application_id: u64,
+    action_type: OfficeActionType,
+    mailed_at: Timestamp,
+) -> Result<(), String> {
+    require_owner(ctx, application_id)?;
+    let (shortened, statutory) = office_action_periods(&action_type);
+    let row = OfficeAction {
+        action_id: 0,
+        application_id,
+        action_type: action_type.clone(),
+        mailed_at,
+        shortened_deadline: add_months(mailed_at, shortened),
+        statutory_deadline: add_months(mailed_at, statutory),
+        extension_months: 0,
+        extension_fee_cents: 0,
+        response_filed_at: None,
+        recorded_by: ctx.sender,
+        created_at: ctx.timestamp,
+    };
+    let inserted = ctx.db.office_action().insert(row);
+    record_event(ctx, application_id, ApplicationEventPayload::OfficeActionMailed(inserted.action_id));
+    if action_type == OfficeActionType::NoticeOfAllowance {
+        advance_stage(ctx, application_id, Stage::Examination, 100);
+    } else {
+        recompute_office_action_progress(ctx, application_id);
+    }
+    recompute_deadlines(ctx, application_id);
+    spacetimedb::log::info!("Office action {} recorded for app_id={}", inserted.action_id, application_id);
+    Ok(())
+}
+
+// Buys additional months (up to the statutory deadline) and records the cumulative fee on the row
+#[reducer]
+pub fn purchase_office_action_extension(
+    ctx: &ReducerContext,
+    action_id: u64,
+    months: u8,
+    entity: EntitySize,
+) -> Result<(), String> {
+    let mut oa = ctx.db.office_action().action_id().find(action_id).ok_or("Office action not found")?;
+    require_owner(ctx, oa.application_id)?;
+    if oa.response_filed_at.is_some() {
+        return Err("A response has already been filed".into());
+    }
+    let (shortened, statutory) = office_action_periods(&oa.action_type);
+    let total = oa.extension_months as u32 + months as u32;
+    if months == 0 || shortened + total > statutory {
+        return Err(format!("At most {} extension months are available", statutory - shortened));
+    }
+    oa.extension_months = total as u8;
+    oa.extension_fee_cents = extension_fee_cents(oa.extension_months, &entity);
+    let application_id = oa.application_id;
+    ctx.db.office_action().action_id().update(oa);
+    recompute_deadlines(ctx, application_id);
+    Ok(())
+}
+
+#[reducer]
+pub fn record_office_action_response(ctx: &ReducerContext, action_id: u64, filed_at: Timestamp) -> Result<(), String> {
+    let mut oa = ctx.db.office_action().action_id().find(action_id).ok_or("Office action not found")?;
+    require_owner(ctx, oa.application_id)?;
+    if oa.response_filed_at.is_some() {
+        return Ok(());
+    }
+    // The response is judged by the date it was filed with the office, not when it is recorded here
+    if filed_at > ctx.timestamp {
+        return Err("Filing date is in the future".into());
+    }
+    if filed_at < oa.mailed_at {
+        return Err("The response cannot be filed before the office action was mailed".into());
+    }
+    if filed_at > oa.statutory_deadline {
+        return Err("The statutory period had expired on the filing date; the application is abandoned".into());
+    }
+    if filed_at > office_action_response_deadline(&oa) {
+        return Err("The response period had passed on the filing date; purchase an extension of time first".into());
+    }
+    let application_id = oa.application_id;
+    oa.response_filed_at = Some(filed_at);
+    ctx.db.office_action().action_id().update(oa);
+    record_event(ctx, application_id, ApplicationEventPayload::OfficeActionResponded(action_id));
+    recompute_office_action_progress(ctx, application_id);
+    recompute_deadlines(ctx, application_id);
+    Ok(())
+}
+
+// ---------- Docketing ----------
+
+const DEFAULT_REMINDER_LEAD_DAYS: [u32; 4] = [90, 30, 7, 1];
+
+// A deadline as computed from the application data, before it is matched against stored rows
+struct ComputedDeadline {
+    kind: DeadlineKind,
+    source_id: u64,
+    label: String,
+    due_at: Timestamp,
+    completed: bool,
+}
+
+fn reminder_lead_days(ctx: &ReducerContext) -> Vec<u32> {
+    let mut leads: Vec<u32> = ctx.db.reminder_lead_time().iter().map(|l| l.lead_days).collect();
+    if leads.is_empty() {
+        leads = DEFAULT_REMINDER_LEAD_DAYS.to_vec();
+    }
+    leads.sort_unstable();
+    leads
+}
+
+// The reminder lead a deadline is due for: the tightest lead time that has been reached (leads are
+// sorted ascending), or 0 once the deadline has passed
+fn reminder_lead_due(now: Timestamp, due_at: Timestamp, leads: &[u32]) -> Option<u32> {
+    if now > due_at {
+        return Some(0);
+    }
+    leads.iter().copied().find(|lead| !older_than(due_at, now, *lead as u64 * 86_400))
+}
+
+// Whether a reminder at least as tight as `lead` already went out for this due date
+fn reminder_already_sent(sent: &[(Timestamp, u32)], due_at: Timestamp, lead: u32) -> bool {
+    sent.iter().any(|(sent_due_at, sent_lead)| *sent_due_at == due_at && *sent_lead <= lead)
+}
+
+fn compute_deadlines(ctx: &ReducerContext, app: &PatentApplication) -> Vec<ComputedDeadline> {
+    let mut out = Vec::new();
+    let closed = matches!(app.status, PatentStatus::Rejected | PatentStatus::Abandoned);
+    if closed {
+        return out;
+    }
+
+    if let Some(filing) = ctx.db.filing_detail().application_id().find(app.application_id) {
+        match filing.filing_type {
+            FilingType::Provisional => out.push(ComputedDeadline {
+                kind: DeadlineKind::ProvisionalConversion,
+                source_id: 0,
+                label: "12 months".to_string(),
+                due_at: add_months(filing.filing_date, 12),
+                completed: ctx
+                    .db
+                    .family_link()
+                    .parent_application_id()
+                    .filter(app.application_id)
+                    .any(|l| l.relationship == FamilyRelationship::ProvisionalBenefit),
+            }),
+            FilingType::Pct => {
+                let priority = earliest_priority_date(ctx, app.application_id).unwrap_or(filing.filing_date);
+                for months in [30, 31] {
+                    out.push(ComputedDeadline {
+                        kind: DeadlineKind::PctNationalPhase,
+                        source_id: 0,
+                        label: format!("{} months", months),
+                        due_at: add_months(priority, months),
+                        completed: false,
+                    });
+                }
+            }
+            FilingType::NonProvisional => {}
+        }
+    }
+
+    for oa in ctx.db.office_action().application_id().filter(app.application_id) {
+        let completed = oa.response_filed_at.is_some();
+        out.push(ComputedDeadline {
+            kind: DeadlineKind::OfficeActionResponse,
+            source_id: oa.action_id,
+            label: format!("{:?} response", oa.action_type),
+            due_at: office_action_response_deadline(&oa),
+            completed,
+        });
+        out.push(ComputedDeadline {
+            kind: DeadlineKind::OfficeActionStatutoryBar,
+            source_id: oa.action_id,
+            label: format!("{:?} statutory bar", oa.action_type),
+            due_at: oa.statutory_deadline,
+            completed,
+        });
+    }
+
+    // A lapsed patent has nothing left to pay
+    let lapsed = ctx.db.grant_record().application_id().find(app.application_id).is_some_and(|g| g.lapsed);
+    for fee in ctx.db.maintenance_fee().application_id().filter(app.application_id).filter(|_| !lapsed) {
+        let completed = fee.paid_at.is_some();
+        out.push(ComputedDeadline {
+            kind: DeadlineKind::MaintenanceFee,
+            source_id: fee.fee_id,
+            label: fee.label.clone(),
+            due_at: fee.due_at,
+            completed,
+        });
+        out.push(ComputedDeadline {
+            kind: DeadlineKind::MaintenanceFee,
+            source_id: fee.fee_id,
+            label: format!("{} with surcharge", fee.label),
+            due_at: fee.grace_ends_at,
+            completed,
+        });
+    }
+
+    out
+}
+
+// Brings the stored deadlines of an application in line with its current data. Completion
+// marked by hand is kept; open deadlines that no longer apply are removed.
+fn recompute_deadlines(ctx: &ReducerContext, application_id: u64) {
+    let Some(app) = ctx.db.patent_application().application_id().find(application_id) else {
+        return;
+    };
+    let computed = compute_deadlines(ctx, &app);
+    let existing: Vec<Deadline> = ctx.db.deadline().application_id().filter(application_id).collect();
+
+    for d in &existing {
+        let still_applies = computed
+            .iter()
+            .any(|c| c.kind == d.kind && c.source_id == d.source_id && c.label == d.label);
+        if !still_applies && !d.completed {
+            ctx.db.deadline().deadline_id().delete(d.deadline_id);
+        }
+    }
+
+    for c in computed {
+        let found = existing
+            .iter()
+            .find(|d| d.kind == c.kind && d.source_id == c.source_id && d.label == c.label);
+        match found {
+            Some(d) => {
+                let completed = d.completed || c.completed;
+                if d.due_at != c.due_at || d.completed != completed {
+                    let mut row = d.clone();
+                    row.due_at = c.due_at;
+                    row.completed = completed;
+                    row.updated_at = ctx.timestamp;
+                    ctx.db.deadline().deadline_id().update(row);
+                }
+            }
+            None => {
+                ctx.db.deadline().insert(Deadline {
+                    deadline_id: 0,
+                    application_id,
+                    kind: c.kind,
+                    source_id: c.source_id,
+                    label: c.label,
+                    due_at: c.due_at,
+                    completed: c.completed,
+                    created_at: ctx.timestamp,
+                    updated_at: ctx.timestamp,
+                });
+            }
+        }
+    }
+}
+
+#[reducer]
+pub fn set_filing_details(
+    ctx: &ReducerContext,
+    application_id: u64,
+    filing_type: FilingType,
+    filing_date: Timestamp,
+    priority_date: Option<Timestamp>,
+) -> Result<(), String> {
+    require_owner(ctx, application_id)?;
+    require_inventors_confirmed(ctx, application_id)?;
+    if let Some(priority) = priority_date {
+        if priority > filing_date {
+            return Err("Priority date cannot be after the filing date".into());
+        }
+    }
+    let row = FilingDetail {
+        application_id,
+        filing_type,
+        filing_date,
+        priority_date,
+        updated_at: ctx.timestamp,
+    };
+    if ctx.db.filing_detail().application_id().find(application_id).is_some() {
+        ctx.db.filing_detail().application_id().update(row);
+    } else {
+        ctx.db.filing_detail().insert(row);
+    }
+    refresh_filing_readiness(ctx, application_id);
+    let members = family_members(ctx, application_id);
+    if members.len() > 1 {
+        rebuild_family_tree(ctx, &[application_id]);
+    }
+    for id in members {
+        recompute_deadlines(ctx, id);
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn complete_deadline(ctx: &ReducerContext, deadline_id: u64) -> Result<(), String> {
+    let mut d = ctx.db.deadline().deadline_id().find(deadline_id).ok_or("Deadline not found")?;
+    require_owner(ctx, d.application_id)?;
+    d.completed = true;
+    d.updated_at = ctx.timestamp;
+    ctx.db.deadline().deadline_id().update(d);
+    Ok(())
+}
+
+#[reducer]
+pub fn acknowledge_reminder(ctx: &ReducerContext, reminder_id: u64) -> Result<(), String> {
+    let mut r = ctx.db.deadline_reminder().reminder_id().find(reminder_id).ok_or("Reminder not found")?;
+    if r.recipient != ctx.sender {
+        return Err("Not authorized to acknowledge this reminder".into());
+    }
+    r.acknowledged = true;
+    ctx.db.deadline_reminder().reminder_id().update(r);
+    Ok(())
+}
+
+#[reducer]
+pub fn set_reminder_lead_days(ctx: &ReducerContext, lead_days: Vec<u32>) -> Result<(), String> {
+    require_admin(ctx)?;
+    if lead_days.is_empty() || lead_days.contains(&0) {
+        return Err("Lead times must be a non-empty list of positive day counts".into());
+    }
+    let existing: Vec<u32> = ctx.db.reminder_lead_time().iter().map(|l| l.lead_days).collect();
+    for lead in existing {
+        ctx.db.reminder_lead_time().lead_days().delete(lead);
+    }
+    for lead in lead_days {
+        if ctx.db.reminder_lead_time().lead_days().find(lead).is_none() {
+            ctx.db.reminder_lead_time().insert(ReminderLeadTime { lead_days: lead });
+        }
+    }
+    Ok(())
+}
+
+// ---------- Grants & Maintenance ----------
+
+// (window opens, due without surcharge, grace ends) in months after grant, with the large entity fee
+const MAINTENANCE_WINDOWS: [(&str, u32, u32, u32, u64); 3] = [
+    ("3.5 years", 36, 42, 48, 215_000),
+    ("7.5 years", 84, 90, 96, 404_000),
+    ("11.5 years", 132, 138, 144, 828_000),
+];
+const MAINTENANCE_SURCHARGE_CENTS: u64 = 54_000;
+
+// Unpaid fee rows for every maintenance window of a patent granted on `grant_date`
+fn maintenance_schedule(application_id: u64, grant_date: Timestamp) -> Vec<MaintenanceFee> {
+    MAINTENANCE_WINDOWS
+        .iter()
+        .map(|(label, opens, due, grace_ends, _)| MaintenanceFee {
+            fee_id: 0,
+            application_id,
+            label: label.to_string(),
+            window_opens_at: add_months(grant_date, *opens),
+            due_at: add_months(grant_date, *due),
+            grace_ends_at: add_months(grant_date, *grace_ends),
+            paid_at: None,
+            fee_cents: 0,
+            surcharge_cents: 0,
+        })
+        .collect()
+}
+
+fn recompute_maintenance_progress(ctx: &ReducerContext, application_id: u64) {
+    let paid = ctx
+        .db
+        .maintenance_fee()
+        .application_id()
+        .filter(application_id)
+        .filter(|f| f.paid_at.is_some())
+        .count();
+    let percent = (paid * 100 / MAINTENANCE_WINDOWS.len()) as u8;
+    set_stage_percent(ctx, application_id, Stage::Maintenance, percent);
+}
+
+// Records grant metadata, moves the application to Granted and opens its maintenance fee schedule.
+// Expiry defaults to 20 years from the non-provisional filing date, or from grant if that is unknown.
+#[reducer]
+pub fn record_grant(
+    ctx: &ReducerContext,
+    application_id: u64,
+    patent_number: String,
+    grant_date: Timestamp,
+    expiry_date: Option<Timestamp>,
+) -> Result<(), String> {
+    let mut app = require_owner(ctx, application_id)?;
+    require_inventors_confirmed(ctx, application_id)?;
+    let patent_number = patent_number.trim().to_string();
+    if patent_number.is_empty() {
+        return Err("Patent number is required".into());
+    }
+    if ctx.db.grant_record().application_id().find(application_id).is_some() {
+        return Err("Grant already recorded for this application".into());
+    }
+    let expiry_date = expiry_date.unwrap_or_else(|| {
+        let term_start = match ctx.db.filing_detail().application_id().find(application_id) {
+            Some(f) if f.filing_type != FilingType::Provisional => f.filing_date,
+            _ => grant_date,
+        };
+        add_months(term_start, 240)
+    });
+    let row = GrantRecord {
+        application_id,
+        patent_number: patent_number.clone(),
+        grant_date,
+        expiry_date,
+        lapsed: false,
+        lapsed_at: None,
+        recorded_at: ctx.timestamp,
+    };
+    if let Err(e) = ctx.db.grant_record().try_insert(row) {
+        return Err(format!("Failed to record grant: {}", e));
+    }
+
+    for fee in maintenance_schedule(application_id, grant_date) {
+        ctx.db.maintenance_fee().insert(fee);
+    }
+
+    if app.status != PatentStatus::Granted {
+        let change = StatusChange { from: app.status.clone(), to: PatentStatus::Granted };
+        app.status = PatentStatus::Granted;
+        app.last_updated = ctx.timestamp;
+        ctx.db.patent_application().application_id().update(app);
+        record_event(ctx, application_id, ApplicationEventPayload::StatusChanged(change));
+        sync_stage_from_status(ctx, application_id, &PatentStatus::Granted);
+    }
+    record_event(ctx, application_id, ApplicationEventPayload::GrantRecorded(patent_number));
+    recompute_maintenance_progress(ctx, application_id);
+    recompute_deadlines(ctx, application_id);
+    Ok(())
+}
+
+#[reducer]
+pub fn record_maintenance_payment(
+    ctx: &ReducerContext,
+    fee_id: u64,
+    entity: EntitySize,
+    paid_at: Timestamp,
+) -> Result<(), String> {
+    let mut fee = ctx.db.maintenance_fee().fee_id().find(fee_id).ok_or("Maintenance fee not found")?;
+    require_owner(ctx, fee.application_id)?;
+    if fee.paid_at.is_some() {
+        return Err("Maintenance fee already paid".into());
+    }
+    if paid_at > ctx.timestamp {
+        return Err("Payment date is in the future".into());
+    }
+    if paid_at < fee.window_opens_at {
+        return Err("The payment window had not opened on the payment date".into());
+    }
+    if paid_at > fee.grace_ends_at {
+        return Err("The grace period had ended on the payment date; the patent has lapsed".into());
+    }
+    let large = MAINTENANCE_WINDOWS
+        .iter()
+        .find(|(label, ..)| *label == fee.label)
+        .map(|w| w.4)
+        .unwrap_or(0);
+    fee.fee_cents = entity_fee_cents(large, &entity);
+    fee.surcharge_cents = if paid_at > fee.due_at { entity_fee_cents(MAINTENANCE_SURCHARGE_CENTS, &entity) } else { 0 };
+    fee.paid_at = Some(paid_at);
+    let application_id = fee.application_id;
+    ctx.db.maintenance_fee().fee_id().update(fee);
+    // A payment made in time but recorded after maintenance_tick lapsed the patent reinstates it
+    let still_overdue = ctx
+        .db
+        .maintenance_fee()
+        .application_id()
+        .filter(application_id)
+        .any(|f| f.paid_at.is_none() && ctx.timestamp > f.grace_ends_at);
+    if let Some(mut grant) = ctx.db.grant_record().application_id().find(application_id) {
+        if grant.lapsed && !still_overdue {
+            grant.lapsed = false;
+            grant.lapsed_at = None;
+            ctx.db.grant_record().application_id().update(grant);
+        }
+    }
+    record_event(ctx, application_id, ApplicationEventPayload::MaintenanceFeePaid(fee_id));
+    recompute_maintenance_progress(ctx, application_id);
+    recompute_deadlines(ctx, application_id);
+    Ok(())
+}
+
+// ---------- Document Versions ----------
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex_encode(&Sha256::digest(bytes))
+}
+
+#[reducer]
+pub fn save_document_version(
+    ctx: &ReducerContext,
+    application_id: u64,
+    doc_type: DocType,
+    content: String,
+    generator: String,
+    parent_version_id: Option<u64>,
+) -> Result<(), String> {
+    require_owner(ctx, application_id)?;
+    insert_document_version(ctx, application_id, doc_type, content, generator, parent_version_id)?;
+    Ok(())
+}
+
+fn latest_document_version(ctx: &ReducerContext, application_id: u64, doc_type: &DocType) -> Option<DocumentVersion> {
+    ctx.db
+        .document_version()
+        .application_id()
+        .filter(application_id)
+        .filter(|v| v.doc_type == *doc_type)
+        .max_by_key(|v| v.version_number)
+}
+
+fn insert_document_version(
+    ctx: &ReducerContext,
+    application_id: u64,
+    doc_type: DocType,
+    content: String,
+    generator: String,
+    parent_version_id: Option<u64>,
+) -> Result<DocumentVersion, String> {
+    let versions: Vec<DocumentVersion> = ctx
+        .db
+        .document_version()
+        .application_id()
+        .filter(application_id)
+        .filter(|v| v.doc_type == doc_type)
+        .collect();
+    if let Some(parent_id) = parent_version_id {
+        if !versions.iter().any(|v| v.version_id == parent_id) {
+            return Err("Parent version must be a version of the same document".into());
+        }
+    }
+    let version_number = versions.iter().map(|v| v.version_number).max().unwrap_or(0) + 1;
+    let inserted = ctx.db.document_version().insert(DocumentVersion {
+        version_id: 0,
+        application_id,
+        doc_type,
+        version_number,
+        content_hash: sha256_hex(content.as_bytes()),
+        content,
+        generator,
+        author: ctx.sender,
+        parent_version_id,
+        filed: false,
+        created_at: ctx.timestamp,
+    });
+    record_event(ctx, application_id, ApplicationEventPayload::DocumentVersionSaved(inserted.version_id));
+    refresh_filing_readiness(ctx, application_id);
+    Ok(inserted)
+}
+
+// Marks a version as the one filed with the office, unpinning any earlier filed version of that document
+#[reducer]
+pub fn pin_filed_version(ctx: &ReducerContext, version_id: u64) -> Result<(), String> {
+    let version = ctx.db.document_version().version_id().find(version_id).ok_or("Document version not found")?;
+    require_owner(ctx, version.application_id)?;
+    let previously_filed: Vec<DocumentVersion> = ctx
+        .db
+        .document_version()
+        .application_id()
+        .filter(version.application_id)
+        .filter(|v| v.doc_type == version.doc_type && v.filed && v.version_id != version_id)
+        .collect();
+    for mut v in previously_filed {
+        v.filed = false;
+        ctx.db.document_version().version_id().update(v);
+    }
+    let application_id = version.application_id;
+    if !version.filed {
+        let mut version = version;
+        version.filed = true;
+        ctx.db.document_version().version_id().update(version);
+        record_event(ctx, application_id, ApplicationEventPayload::DocumentVersionFiled(version_id));
+    }
+    Ok(())
+}
+
+// ---------- Document Generation Jobs ----------
+
+const DOC_JOB_LEASE_SECS: u64 = 300;
+const DOC_JOB_MAX_ATTEMPTS: u32 = 5;
+const DOC_JOB_BACKOFF_BASE_SECS: u64 = 30;
+const DOC_JOB_BACKOFF_MAX_SECS: u64 = 3_600;
+
+fn require_doc_worker(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.doc_worker().identity().find(ctx.sender).is_some() {
+        Ok(())
+    } else {
+        Err("Only a registered document worker can perform this action".into())
+    }
+}
+
+fn add_secs(at: Timestamp, secs: u64) -> Timestamp {
+    Timestamp::from_micros_since_unix_epoch(at.to_micros_since_unix_epoch() + (secs * 1_000_000) as i64)
+}
+
+// Exponential backoff after the given number of attempts: 30s, 60s, 120s, ... capped at an hour
+fn doc_job_backoff_secs(attempts: u32) -> u64 {
+    let exp = attempts.saturating_sub(1).min(16);
+    (DOC_JOB_BACKOFF_BASE_SECS << exp).min(DOC_JOB_BACKOFF_MAX_SECS)
+}
+
+fn leased_job(ctx: &ReducerContext, job_id: u64) -> Result<DocJob, String> {
+    let job = ctx.db.doc_job().job_id().find(job_id).ok_or("Job not found")?;
+    if job.status != DocJobStatus::Leased || job.leased_by != Some(ctx.sender) {
+        return Err("Job is not leased by this worker".into());
+    }
+    Ok(job)
+}
+
+// Requeues a job with backoff, or fails it for good once it is out of attempts
+fn retry_or_fail_job(ctx: &ReducerContext, mut job: DocJob, error: String) -> Result<(), String> {
+    job.leased_by = None;
+    job.lease_expires_at = None;
+    job.last_error = error.clone();
+    job.updated_at = ctx.timestamp;
+    let (application_id, doc_type) = (job.application_id, job.doc_type.clone());
+    if job.attempts >= job.max_attempts {
+        // The job is finished either way; a document status that cannot move to Failed is logged
+        // rather than returned so the caller's other work is not rolled back
+        let job_id = job.job_id;
+        job.status = DocJobStatus::Failed;
+        ctx.db.doc_job().job_id().update(job);
+        if let Err(e) = set_document_generation(ctx, application_id, doc_type, DocGenStatus::Failed, error) {
+            spacetimedb::log::warn!("Doc job {} failed but its document status was not updated: {}", job_id, e);
+        }
+    } else {
+        // The document stays InProgress while the job waits, but this attempt is over and
+        // counts as a failure; backoff time is not part of any attempt
+        job.status = DocJobStatus::Queued;
+        job.available_at = add_secs(ctx.timestamp, doc_job_backoff_secs(job.attempts));
+        ctx.db.doc_job().job_id().update(job);
+        let doc = ctx
+            .db
+            .document_generation()
+            .application_id()
+            .filter(application_id)
+            .find(|d| d.doc_type == doc_type);
+        if let Some(doc) = doc {
+            close_doc_gen_attempt(ctx, doc.doc_id, &DocGenStatus::Failed);
+        }
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn register_doc_worker(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    if ctx.db.doc_worker().identity().find(identity).is_none() {
+        ctx.db.doc_worker().insert(DocWorker {
+            identity,
+            added_at: ctx.timestamp,
+        });
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn remove_doc_worker(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    ctx.db.doc_worker().identity().delete(identity);
+    Ok(())
+}
+
+#[reducer]
+pub fn enqueue_doc_job(ctx: &ReducerContext, application_id: u64, doc_type: DocType, priority: u32) -> Result<(), String> {
+    require_owner(ctx, application_id)?;
+    let completed = ctx
+        .db
+        .document_generation()
+        .application_id()
+        .filter(application_id)
+        .any(|d| d.doc_type == doc_type && d.status == DocGenStatus::Completed);
+    if completed {
+        return Err(format!("{:?} has already been generated; use regenerate_document to generate it again", doc_type));
+    }
+    queue_doc_job(ctx, application_id, doc_type, priority)
+}
+
+// Queues a fresh generation of a document that was already generated; the new output is saved as a new version
+#[reducer]
+pub fn regenerate_document(ctx: &ReducerContext, application_id: u64, doc_type: DocType, priority: u32) -> Result<(), String> {
+    require_owner(ctx, application_id)?;
+    queue_doc_job(ctx, application_id, doc_type, priority)
+}
+
+fn queue_doc_job(ctx: &ReducerContext, application_id: u64, doc_type: DocType, priority: u32) -> Result<(), String> {
+    if doc_job_active(ctx, application_id, &doc_type) {
+        return Err(format!("A {:?} job is already queued for this application", doc_type));
+    }
+    ctx.db.doc_job().insert(DocJob {
+        job_id: 0,
+        application_id,
+        doc_type,
+        priority,
+        status: DocJobStatus::Queued,
+        attempts: 0,
+        max_attempts: DOC_JOB_MAX_ATTEMPTS,
+        leased_by: None,
+        lease_expires_at: None,
+        available_at: ctx.timestamp,
+        last_error: String::new(),
+        result_version_id: None,
+        created_by: ctx.sender,
+        created_at: ctx.timestamp,
+        updated_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+// Leases the highest-priority available job (oldest first on ties) to the calling worker, who
+// picks it up through its subscription on leased_by
+#[reducer]
+pub fn claim_next_job(ctx: &ReducerContext) -> Result<(), String> {
+    require_doc_worker(ctx)?;
+    let now = ctx.timestamp;
+    let next = ctx
+        .db
+        .doc_job()
+        .iter()
+        .filter(|j| j.status == DocJobStatus::Queued && j.available_at <= now)
+        .max_by_key(|j| (j.priority, std::cmp::Reverse(j.job_id)));
+    let Some(mut job) = next else {
+        return Err("No jobs available".into());
+    };
+    job.status = DocJobStatus::Leased;
+    job.attempts += 1;
+    job.leased_by = Some(ctx.sender);
+    job.lease_expires_at = Some(add_secs(now, DOC_JOB_LEASE_SECS));
+    job.updated_at = now;
+    let (job_id, application_id, doc_type) = (job.job_id, job.application_id, job.doc_type.clone());
+    ctx.db.doc_job().job_id().update(job);
+    set_document_generation(ctx, application_id, doc_type, DocGenStatus::InProgress, String::new())?;
+    spacetimedb::log::info!("Doc job {} leased by {}", job_id, ctx.sender);
+    Ok(())
+}
+
+#[reducer]
+pub fn heartbeat_job(ctx: &ReducerContext, job_id: u64) -> Result<(), String> {
+    let mut job = leased_job(ctx, job_id)?;
+    job.lease_expires_at = Some(add_secs(ctx.timestamp, DOC_JOB_LEASE_SECS));
+    job.updated_at = ctx.timestamp;
+    ctx.db.doc_job().job_id().update(job);
+    Ok(())
+}
+
+#[reducer]
+pub fn complete_job(ctx: &ReducerContext, job_id: u64, content: String, generator: String) -> Result<(), String> {
+    let mut job = leased_job(ctx, job_id)?;
+    let parent = latest_document_version(ctx, job.application_id, &job.doc_type).map(|v| v.version_id);
+    let version = insert_document_version(ctx, job.application_id, job.doc_type.clone(), content, generator, parent)?;
+    job.status = DocJobStatus::Completed;
+    job.leased_by = None;
+    job.lease_expires_at = None;
+    job.last_error = String::new();
+    job.result_version_id = Some(version.version_id);
+    job.updated_at = ctx.timestamp;
+    let (job_id, application_id, doc_type) = (job.job_id, job.application_id, job.doc_type.clone());
+    ctx.db.doc_job().job_id().update(job);
+    // The output is saved as a version even if the document status cannot move to Completed
+    if let Err(e) = set_document_generation(ctx, application_id, doc_type, DocGenStatus::Completed, String::new()) {
+        spacetimedb::log::warn!("Doc job {} completed but its document status was not updated: {}", job_id, e);
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn fail_job(ctx: &ReducerContext, job_id: u64, error: String) -> Result<(), String> {
+    let job = leased_job(ctx, job_id)?;
+    if error.trim().is_empty() {
+        return Err("An error message is required when a job fails".into());
+    }
+    retry_or_fail_job(ctx, job, error)
+}
+
+// ---------- Filing Readiness ----------
+
+// Subclasses whose applications disclose nucleotide or amino acid sequences
+const SEQUENCE_LISTING_SUBCLASSES: [&str; 3] = ["C12N", "C12Q", "C07K"];
+
+// Documents needed for filing, based on the filing type, the offices filed with and whether
+// sequences are disclosed. Applications without filing details are treated as US non-provisionals.
+fn required_documents(ctx: &ReducerContext, application_id: u64) -> Vec<DocType> {
+    let filing_type = ctx
+        .db
+        .filing_detail()
+        .application_id()
+        .find(application_id)
+        .map(|f| f.filing_type)
+        .unwrap_or(FilingType::NonProvisional);
+    let mut required = match filing_type {
+        FilingType::Provisional => vec![DocType::Spec, DocType::Drawings, DocType::ApplicationDataSheet],
+        FilingType::NonProvisional => vec![
+            DocType::Spec,
+            DocType::Claims,
+            DocType::Drawings,
+            DocType::Abstract,
+            DocType::InventorDeclaration,
+            DocType::ApplicationDataSheet,
+        ],
+        FilingType::Pct => vec![DocType::Spec, DocType::Claims, DocType::Drawings, DocType::Abstract],
+    };
+
+    let mut add = |doc: DocType| {
+        if !required.contains(&doc) {
+            required.push(doc);
+        }
+    };
+    for f in ctx.db.jurisdiction_filing().application_id().filter(application_id) {
+        match f.office {
+            PatentOffice::Uspto => {
+                add(DocType::InventorDeclaration);
+                add(DocType::ApplicationDataSheet);
+            }
+            // Foreign applicants act through a local representative
+            PatentOffice::Jpo | PatentOffice::Cnipa => add(DocType::PowerOfAttorney),
+            PatentOffice::Epo | PatentOffice::Wipo => {}
+        }
+    }
+    let discloses_sequences = ctx
+        .db
+        .classification()
+        .application_id()
+        .filter(application_id)
+        .any(|c| c.source != ClassificationSource::Suggested && SEQUENCE_LISTING_SUBCLASSES.contains(&classification_subclass(&c.code).as_str()));
+    if discloses_sequences {
+        add(DocType::SequenceListing);
+    }
+    required
+}
+
+fn refresh_filing_readiness(ctx: &ReducerContext, application_id: u64) {
+    let stale: Vec<u64> = ctx.db.filing_readiness().application_id().filter(application_id).map(|r| r.row_id).collect();
+    for id in stale {
+        ctx.db.filing_readiness().row_id().delete(id);
+    }
+    for doc_type in required_documents(ctx, application_id) {
+        let generation = ctx
+            .db
+            .document_generation()
+            .application_id()
+            .filter(application_id)
+            .find(|d| d.doc_type == doc_type);
+        let has_version = latest_document_version(ctx, application_id, &doc_type).is_some();
+        let exists = has_version || generation.as_ref().is_some_and(|d| d.status != DocGenStatus::NotStarted);
+        let completed = generation.is_some_and(|d| d.status == DocGenStatus::Completed);
+        ctx.db.filing_readiness().insert(FilingReadiness {
+            row_id: 0,
+            application_id,
+            doc_type,
+            exists,
+            completed,
+            updated_at: ctx.timestamp,
+        });
+    }
+}
+
+// ---------- Information Disclosure Statements ----------
+
+const PATENT_COUNTRY_CODES: [&str; 10] = ["US", "EP", "WO", "JP", "CN", "KR", "DE", "GB", "FR", "CA"];
+
+// First token like "US10123456B2", "US 2019/0123456 A1" or "WO2019123456" in the text, normalized
+fn parse_publication_number(text: &str) -> Option<String> {
+    let chars: Vec<char> = text.to_uppercase().chars().collect();
+    for start in 0..chars.len().saturating_sub(2) {
+        if start > 0 && chars[start - 1].is_ascii_alphanumeric() {
+            continue;
+        }
+        let code: String = chars[start..start + 2].iter().collect();
+        if !PATENT_COUNTRY_CODES.contains(&code.as_str()) {
+            continue;
+        }
+        let mut number = code;
+        let mut digits = 0;
+        let mut i = start + 2;
+        if chars.get(i) == Some(&' ') {
+            i += 1;
+        }
+        while let Some(&c) = chars.get(i) {
+            let separator = matches!(c, ' ' | '/' | ',') && digits > 0 && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit());
+            if c.is_ascii_digit() {
+                number.push(c);
+                digits += 1;
+            } else if !separator {
+                break;
+            }
+            i += 1;
+        }
+        if digits < 5 {
+            continue;
+        }
+
+        // Kind code such as A1 or B2; after a space only a letter-digit pair counts
+        let spaced = chars.get(i) == Some(&' ');
+        let k = if spaced { i + 1 } else { i };
+        let letter = chars.get(k).copied().filter(|c| c.is_ascii_uppercase());
+        let digit = chars.get(k + 1).copied().filter(|c| c.is_ascii_digit());
+        let ends = |at: usize| !chars.get(at).is_some_and(|c| c.is_ascii_alphanumeric());
+        if let Some(letter) = letter {
+            if let Some(digit) = digit.filter(|_| ends(k + 2)) {
+                number.push(letter);
+                number.push(digit);
+            } else if !spaced && ends(k + 1) {
+                number.push(letter);
+            }
+        }
+        return Some(number);
+    }
+    None
+}
+
+fn reference_kind(publication_number: &str) -> ReferenceKind {
+    if publication_number.is_empty() {
+        return ReferenceKind::NonPatentLiterature;
+    }
+    if !publication_number.starts_with("US") {
+        return ReferenceKind::ForeignPatent;
+    }
+    // US pre-grant publications carry an 11-digit number starting with the year
+    let digits = publication_number[2..].chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 11 {
+        ReferenceKind::UsPublication
+    } else {
+        ReferenceKind::UsPatent
+    }
+}
+
+fn normalize_url(url: &str) -> String {
+    let url = url.trim().to_lowercase();
+    let url = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://")).unwrap_or(&url);
+    let url = url.strip_prefix("www.").unwrap_or(url);
+    url.trim_end_matches('/').to_string()
+}
+
+// Publication number when there is one, otherwise the normalized URL, otherwise the summary
+fn citation_dedupe_key(publication_number: &str, result: &PriorArtResult) -> String {
+    if !publication_number.is_empty() {
+        format!("pub:{}", publication_number)
+    } else if !result.url.trim().is_empty() {
+        format!("url:{}", normalize_url(&result.url))
+    } else {
+        format!("npl:{}", sha256_hex(result.summary.trim().to_lowercase().as_bytes()))
+    }
+}
+
+fn ensure_citation(ctx: &ReducerContext, result: &PriorArtResult) -> PriorArtCitation {
+    if let Some(c) = ctx.db.prior_art_citation().result_id().find(result.result_id) {
+        return c;
+    }
+    let publication_number = parse_publication_number(&result.url)
+        .or_else(|| parse_publication_number(&result.summary))
+        .unwrap_or_default();
+    ctx.db.prior_art_citation().insert(PriorArtCitation {
+        result_id: result.result_id,
+        application_id: result.application_id,
+        kind: reference_kind(&publication_number),
+        dedupe_key: citation_dedupe_key(&publication_number, result),
+        publication_number,
+        publication_date: None,
+        cited_in_ids_id: None,
+    })
+}
+
+fn format_date(at: Timestamp) -> String {
+    let (year, month, day) = civil_from_days(at.to_micros_since_unix_epoch().div_euclid(MICROS_PER_DAY));
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// Corrects the parsed publication number or adds the publication date of a reference
+#[reducer]
+pub fn annotate_prior_art_citation(
+    ctx: &ReducerContext,
+    result_id: u64,
+    publication_number: String,
+    publication_date: Option<Timestamp>,
+) -> Result<(), String> {
+    let result = ctx.db.prior_art_result().result_id().find(result_id).ok_or("Prior art result not found")?;
+    require_owner(ctx, result.application_id)?;
+    let mut citation = ensure_citation(ctx, &result);
+    let publication_number: String = publication_number
+        .to_uppercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+    citation.kind = reference_kind(&publication_number);
+    citation.dedupe_key = citation_dedupe_key(&publication_number, &result);
+    citation.publication_number = publication_number;
+    citation.publication_date = publication_date;
+    ctx.db.prior_art_citation().result_id().update(citation);
+    Ok(())
+}
+
+// Builds the next IDS from prior art not disclosed in an earlier IDS and saves it as an
+// InformationDisclosureStatement document version
+#[reducer]
+pub fn generate_ids(ctx: &ReducerContext, application_id: u64) -> Result<(), String> {
+    let app = require_owner(ctx, application_id)?;
+    let results: Vec<PriorArtResult> = ctx.db.prior_art_result().application_id().filter(application_id).collect();
+    let citations: Vec<PriorArtCitation> = results.iter().map(|r| ensure_citation(ctx, r)).collect();
+
+    let already_cited: Vec<String> = citations
+        .iter()
+        .filter(|c| c.cited_in_ids_id.is_some())
+        .map(|c| c.dedupe_key.clone())
+        .collect();
+    let mut new_refs: Vec<(&PriorArtCitation, &PriorArtResult)> = Vec::new();
+    for (c, r) in citations.iter().zip(&results) {
+        if already_cited.contains(&c.dedupe_key) || new_refs.iter().any(|(n, _)| n.dedupe_key == c.dedupe_key) {
+            continue;
+        }
+        new_refs.push((c, r));
+    }
+    if new_refs.is_empty() {
+        return Err("No new references to disclose".into());
+    }
+
+    let sequence = ctx.db.ids_filing().application_id().filter(application_id).count() as u32 + 1;
+    let mut content = format!(
+        "INFORMATION DISCLOSURE STATEMENT No. {}\nApplication: {} ({})\n",
+        sequence, app.title, application_id
+    );
+    let sections = [
+        (ReferenceKind::UsPatent, "U.S. PATENTS"),
+        (ReferenceKind::UsPublication, "U.S. PATENT APPLICATION PUBLICATIONS"),
+        (ReferenceKind::ForeignPatent, "FOREIGN PATENT DOCUMENTS"),
+        (ReferenceKind::NonPatentLiterature, "NON-PATENT LITERATURE DOCUMENTS"),
+    ];
+    for (kind, heading) in sections {
+        let refs: Vec<&(&PriorArtCitation, &PriorArtResult)> = new_refs.iter().filter(|(c, _)| c.kind == kind).collect();
+        if refs.is_empty() {
+            continue;
+        }
+        content.push_str(&format!("\n{}\n", heading));
+        for (n, (c, r)) in refs.iter().enumerate() {
+            let date = c.publication_date.map(format_date).unwrap_or_else(|| "date unknown".to_string());
+            if kind == ReferenceKind::NonPatentLiterature {
+                content.push_str(&format!("{}. {} — {} ({}) {}\n", n + 1, r.source, r.summary, date, r.url));
+            } else {
+                content.push_str(&format!("{}. {} ({}) {}\n", n + 1, c.publication_number, date, r.source));
+            }
+        }
+    }
+
+    let parent = latest_document_version(ctx, application_id, &DocType::InformationDisclosureStatement).map(|v| v.version_id);
+    let version = insert_document_version(
+        ctx,
+        application_id,
+        DocType::InformationDisclosureStatement,
+        content,
+        "ids-generator".to_string(),
+        parent,
+    )?;
+    let ids = ctx.db.ids_filing().insert(IdsFiling {
+        ids_id: 0,
+        application_id,
+        sequence,
+        version_id: version.version_id,
+        reference_count: new_refs.len() as u32,
+        created_by: ctx.sender,
+        created_at: ctx.timestamp,
+    });
+
+    // Every result sharing a disclosed key counts as cited, duplicates included
+    let new_keys: Vec<String> = new_refs.iter().map(|(c, _)| c.dedupe_key.clone()).collect();
+    for c in citations {
+        if c.cited_in_ids_id.is_none() && new_keys.contains(&c.dedupe_key) {
+            let mut c = c;
+            c.cited_in_ids_id = Some(ids.ids_id);
+            ctx.db.prior_art_citation().result_id().update(c);
+        }
+    }
+    record_event(ctx, application_id, ApplicationEventPayload::IdsGenerated(ids.ids_id));
+    Ok(())
+}
+
+// ---------- XML Export / Import ----------
+
+const ST96_SCHEMA_VERSION: &str = "ST96-V6_0";
+const ST96_PAT_NS: &str = "http://www.wipo.int/standards/XMLSchema/ST96/Patent";
+const ST96_COM_NS: &str = "http://www.wipo.int/standards/XMLSchema/ST96/Common";
+
+// Splits claims text into individual claims, each starting at a line like "1." or "2)".
+// Joining the result with newlines gives back the original text.
+fn split_claims(claims_text: &str) -> Vec<String> {
+    let mut claims: Vec<String> = Vec::new();
+    for line in claims_text.split('\n') {
+        let trimmed = line.trim_start();
+        let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+        let numbered = digits > 0 && matches!(trimmed[digits..].chars().next(), Some('.') | Some(')'));
+        match claims.last_mut() {
+            Some(last) if !numbered => {
+                last.push('\n');
+                last.push_str(line);
+            }
+            _ => claims.push(line.to_string()),
+        }
+    }
+    claims
+}
+
+// Filed version of a document if pinned, else the latest version, else `fallback`
+fn current_document_text(ctx: &ReducerContext, application_id: u64, doc_type: DocType, fallback: &str) -> String {
+    let versions: Vec<DocumentVersion> = ctx
+        .db
+        .document_version()
+        .application_id()
+        .filter(application_id)
+        .filter(|v| v.doc_type == doc_type)
+        .collect();
+    versions
+        .iter()
+        .find(|v| v.filed)
+        .or_else(|| versions.iter().max_by_key(|v| v.version_number))
+        .map(|v| v.content.clone())
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+fn xml_element(out: &mut String, indent: usize, name: &str, text: &str) {
+    out.push_str(&format!("{}<{}>{}</{}>\n", "  ".repeat(indent), name, escape_markup(text), name));
+}
+
+// Everything an ST.96 export carries, independent of where it came from
+#[derive(Debug, Clone, PartialEq)]
+struct St96Application {
+    identification: Option<St96Identification>,
+    title: String,
+    abstract_text: String,
+    claims: Vec<String>,
+    inventors: Vec<St96Inventor>,
+    citations: Vec<St96Citation>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct St96Identification {
+    office: PatentOffice,
+    application_number: String,
+    filing_date: Timestamp,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct St96Inventor {
+    sequence: u32,
+    name: String,
+    email: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct St96Citation {
+    publication_number: String, // empty for non-patent literature
+    text: String,
+    url: String,
+    source: String,
+}
+
+// Client-supplied XML is bounded before parsing
+const MAX_XML_BYTES: usize = 1 << 20;
+const MAX_XML_DEPTH: usize = 16;
+
+fn st96_application(ctx: &ReducerContext, app: &PatentApplication) -> St96Application {
+    let id = app.application_id;
+    let filings: Vec<JurisdictionFiling> = ctx.db.jurisdiction_filing().application_id().filter(id).collect();
+    let identification = filings
+        .iter()
+        .find(|f| f.office == PatentOffice::Uspto)
+        .or(filings.first())
+        .map(|f| St96Identification {
+            office: f.office.clone(),
+            application_number: f.application_number.clone(),
+            filing_date: f.filing_date,
+        });
+
+    let mut inventor_rows: Vec<ApplicationInventor> = ctx.db.application_inventor().application_id().filter(id).collect();
+    inventor_rows.sort_by_key(|i| i.position);
+    let inventors = inventor_rows
+        .iter()
+        .map(|inv| {
+            let profile = ctx.db.inventor().identity().find(inv.inventor);
+            St96Inventor {
+                sequence: inv.position,
+                name: profile.as_ref().map(|p| p.name.clone()).unwrap_or_default(),
+                email: profile.map(|p| p.email).unwrap_or_default(),
+            }
+        })
+        .collect();
+
+    let citations = ctx
+        .db
+        .prior_art_result()
+        .application_id()
+        .filter(id)
+        .map(|r| {
+            let publication_number = ctx
+                .db
+                .prior_art_citation()
+                .result_id()
+                .find(r.result_id)
+                .map(|c| c.publication_number)
+                .or_else(|| parse_publication_number(&r.url))
+                .unwrap_or_default();
+            St96Citation { publication_number, text: r.summary, url: r.url, source: r.source }
+        })
+        .collect();
+
+    St96Application {
+        identification,
+        title: app.title.clone(),
+        abstract_text: current_document_text(ctx, id, DocType::Abstract, &app.abstract_text),
+        claims: split_claims(&current_document_text(ctx, id, DocType::Claims, &app.claims_text)),
+        inventors,
+        citations,
+    }
+}
+
+fn st96_application_xml(doc: &St96Application) -> String {
+    let mut x = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    x.push_str(&format!(
+        "<pat:PatentApplication xmlns:pat=\"{}\" xmlns:com=\"{}\" com:st96Version=\"V6_0\">\n",
+        ST96_PAT_NS, ST96_COM_NS
+    ));
+
+    if let Some(ident) = &doc.identification {
+        x.push_str("  <pat:ApplicationIdentification>\n");
+        xml_element(&mut x, 2, "com:IPOfficeCode", office_segment(&ident.office));
+        xml_element(&mut x, 2, "com:ApplicationNumberText", &ident.application_number);
+        xml_element(&mut x, 2, "pat:FilingDate", &format_date(ident.filing_date));
+        x.push_str("  </pat:ApplicationIdentification>\n");
+    }
+    xml_element(&mut x, 1, "pat:InventionTitle", &doc.title);
+
+    if !doc.inventors.is_empty() {
+        x.push_str("  <pat:PartyBag>\n    <pat:InventorBag>\n");
+        for inv in &doc.inventors {
+            x.push_str(&format!("      <pat:Inventor com:sequenceNumber=\"{}\">\n        <com:Contact>\n", inv.sequence));
+            xml_element(&mut x, 5, "com:EntityName", &inv.name);
+            xml_element(&mut x, 5, "com:EmailAddressText", &inv.email);
+            x.push_str("        </com:Contact>\n      </pat:Inventor>\n");
+        }
+        x.push_str("    </pat:InventorBag>\n  </pat:PartyBag>\n");
+    }
+
+    x.push_str("  <pat:Abstract>\n");
+    xml_element(&mut x, 2, "com:P", &doc.abstract_text);
+    x.push_str("  </pat:Abstract>\n  <pat:Claims>\n");
+    for (n, claim) in doc.claims.iter().enumerate() {
+        x.push_str(&format!("    <pat:Claim com:sequenceNumber=\"{}\">\n", n + 1));
+        xml_element(&mut x, 3, "pat:ClaimText", claim);
+        x.push_str("    </pat:Claim>\n");
+    }
+    x.push_str("  </pat:Claims>\n");
+
+    if !doc.citations.is_empty() {
+        x.push_str("  <pat:ReferenceCitationBag>\n");
+        for c in &doc.citations {
+            if c.publication_number.is_empty() {
+                x.push_str("    <pat:NPLCitation>\n");
+                xml_element(&mut x, 3, "pat:NPLReferenceText", &c.text);
+            } else {
+                x.push_str("    <pat:PatentCitation>\n");
+                xml_element(&mut x, 3, "pat:PatentDocumentNumber", &c.publication_number);
+                xml_element(&mut x, 3, "pat:CitationText", &c.text);
+            }
+            xml_element(&mut x, 3, "com:WebAddressURI", &c.url);
+            xml_element(&mut x, 3, "pat:CitationSource", &c.source);
+            x.push_str(if c.publication_number.is_empty() { "    </pat:NPLCitation>\n" } else { "    </pat:PatentCitation>\n" });
+        }
+        x.push_str("  </pat:ReferenceCitationBag>\n");
+    }
+    x.push_str("</pat:PatentApplication>\n");
+    x
+}
+
+fn office_from_code(code: &str) -> Result<PatentOffice, String> {
+    match code.trim() {
+        "US" => Ok(PatentOffice::Uspto),
+        "EP" => Ok(PatentOffice::Epo),
+        "JP" => Ok(PatentOffice::Jpo),
+        "CN" => Ok(PatentOffice::Cnipa),
+        "WO" => Ok(PatentOffice::Wipo),
+        other => Err(format!("Unsupported IPOfficeCode '{}'", other)),
+    }
+}
+
+// Text of the element currently open, keyed by its local name and the names of its ancestors
+fn st96_text_target<'a>(path: &[String], doc: &'a mut St96Application, ident: &'a mut [String; 3]) -> Option<&'a mut String> {
+    let names: Vec<&str> = path.iter().map(String::as_str).collect();
+    match names.as_slice() {
+        ["PatentApplication", "InventionTitle"] => Some(&mut doc.title),
+        ["PatentApplication", "Abstract", "P"] => Some(&mut doc.abstract_text),
+        ["PatentApplication", "Claims", "Claim", "ClaimText"] => doc.claims.last_mut(),
+        ["PatentApplication", "ApplicationIdentification", "IPOfficeCode"] => Some(&mut ident[0]),
+        ["PatentApplication", "ApplicationIdentification", "ApplicationNumberText"] => Some(&mut ident[1]),
+        ["PatentApplication", "ApplicationIdentification", "FilingDate"] => Some(&mut ident[2]),
+        ["PatentApplication", "PartyBag", "InventorBag", "Inventor", "Contact", field] => {
+            let inv = doc.inventors.last_mut()?;
+            match *field {
+                "EntityName" => Some(&mut inv.name),
+                "EmailAddressText" => Some(&mut inv.email),
+                _ => None,
+            }
+        }
+        ["PatentApplication", "ReferenceCitationBag", "PatentCitation" | "NPLCitation", field] => {
+            let c = doc.citations.last_mut()?;
+            match *field {
+                "PatentDocumentNumber" => Some(&mut c.publication_number),
+                "CitationText" | "NPLReferenceText" => Some(&mut c.text),
+                "WebAddressURI" => Some(&mut c.url),
+                "CitationSource" => Some(&mut c.source),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn parse_st96_application(xml: &str) -> Result<St96Application, String> {
+    use quick_xml::events::Event;
+
+    if xml.len() > MAX_XML_BYTES {
+        return Err(format!("XML payload exceeds {} bytes", MAX_XML_BYTES));
+    }
+    let mut reader = quick_xml::Reader::from_str(xml);
+    reader.config_mut().expand_empty_elements = true;
+
+    let mut doc = St96Application {
+        identification: None,
+        title: String::new(),
+        abstract_text: String::new(),
+        claims: Vec::new(),
+        inventors: Vec::new(),
+        citations: Vec::new(),
+    };
+    let mut ident: [String; 3] = Default::default();
+    let mut path: Vec<String> = Vec::new();
+    let mut seen_root = false;
+    loop {
+        let event = reader.read_event().map_err(|e| format!("Invalid XML at byte {}: {}", reader.buffer_position(), e))?;
+        let text = match event {
+            Event::Start(e) => {
+                if path.len() >= MAX_XML_DEPTH {
+                    return Err(format!("XML nesting exceeds {} levels", MAX_XML_DEPTH));
+                }
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+                if path.is_empty() {
+                    if seen_root || name != "PatentApplication" {
+                        return Err("Root element must be a single PatentApplication".into());
+                    }
+                    seen_root = true;
+                }
+                let parent = path.last().map(String::as_str);
+                match (parent, name.as_str()) {
+                    (Some("Claims"), "Claim") => doc.claims.push(String::new()),
+                    (Some("InventorBag"), "Inventor") => {
+                        let sequence = e
+                            .attributes()
+                            .flatten()
+                            .find(|a| a.key.local_name().as_ref() == b"sequenceNumber")
+                            .and_then(|a| a.unescape_value().ok()?.parse().ok())
+                            .unwrap_or(doc.inventors.len() as u32 + 1);
+                        doc.inventors.push(St96Inventor { sequence, name: String::new(), email: String::new() });
+                    }
+                    (Some("ReferenceCitationBag"), "PatentCitation" | "NPLCitation") => doc.citations.push(St96Citation {
+                        publication_number: String::new(),
+                        text: String::new(),
+                        url: String::new(),
+                        source: String::new(),
+                    }),
+                    _ => {}
+                }
+                path.push(name);
+                continue;
+            }
+            Event::End(_) => {
+                path.pop();
+                continue;
+            }
+            Event::Text(t) => t.decode().map_err(|e| e.to_string())?.into_owned(),
+            Event::CData(t) => t.decode().map_err(|e| e.to_string())?.into_owned(),
+            Event::GeneralRef(r) => {
+                let name = r.decode().map_err(|e| e.to_string())?;
+                match r.resolve_char_ref().map_err(|e| e.to_string())? {
+                    Some(ch) => ch.to_string(),
+                    None => quick_xml::escape::resolve_predefined_entity(&name)
+                        .ok_or_else(|| format!("Unknown entity &{};", name))?
+                        .to_string(),
+                }
+            }
+            Event::Eof => break,
+            _ => continue,
+        };
+        if let Some(target) = st96_text_target(&path, &mut doc, &mut ident) {
+            target.push_str(&text);
+        }
+    }
+    if !seen_root {
+        return Err("Root element must be a single PatentApplication".into());
+    }
+    if doc.title.trim().is_empty() {
+        return Err("InventionTitle is required".into());
+    }
+
+    let [office, application_number, filing_date] = ident;
+    if !application_number.trim().is_empty() {
+        doc.identification = Some(St96Identification {
+            office: office_from_code(&office)?,
+            application_number: application_number.trim().to_string(),
+            filing_date: parse_date(&filing_date)?,
+        });
+    }
+    Ok(doc)
+}
+
+#[reducer]
+pub fn export_application_xml(ctx: &ReducerContext, application_id: u64) -> Result<(), String> {
+    let app = require_owner(ctx, application_id)?;
+    let content = st96_application_xml(&st96_application(ctx, &app));
+    ctx.db.export_artifact().insert(ExportArtifact {
+        artifact_id: 0,
+        owner: ctx.sender,
+        application_id: Some(application_id),
+        format: ExportFormat::St96Xml,
+        schema_version: ST96_SCHEMA_VERSION.to_string(),
+        file_name: format!("application-{}-st96.xml", application_id),
+        content_type: "application/xml".to_string(),
+        content_hash: sha256_hex(content.as_bytes()),
+        content,
+        created_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+// Creates a Draft application from ST.96 XML. Inventors are matched to registered profiles by
+// email and citations become prior art results. The office identification becomes a jurisdiction
+// filing that stays Draft until inventorship is confirmed and the owner moves the application on.
+#[reducer]
+pub fn import_application_xml(ctx: &ReducerContext, xml: String) -> Result<(), String> {
+    let parsed = parse_st96_application(&xml)?;
+    let app = ctx.db.patent_application().insert(PatentApplication {
+        application_id: 0,
+        owner: ctx.sender,
+        title: parsed.title.clone(),
+        abstract_text: parsed.abstract_text,
+        claims_text: parsed.claims.join("\n"),
+        status: PatentStatus::Draft,
+        created_at: ctx.timestamp,
+        last_updated: ctx.timestamp,
+    });
+    let id = app.application_id;
+    record_event(ctx, id, ApplicationEventPayload::Submitted(parsed.title));
+    sync_stage_from_status(ctx, id, &app.status);
+
+    if let Some(ident) = parsed.identification {
+        ctx.db.jurisdiction_filing().insert(JurisdictionFiling {
+            filing_id: 0,
+            application_id: id,
+            office: ident.office,
+            application_number: ident.application_number,
+            filing_date: ident.filing_date,
+            status: PatentStatus::Draft,
+            created_at: ctx.timestamp,
+            updated_at: ctx.timestamp,
+        });
+    }
+
+    for inv in parsed.inventors {
+        let email = inv.email.trim().to_lowercase();
+        let profile = ctx.db.inventor().iter().find(|p| !email.is_empty() && p.email.trim().to_lowercase() == email);
+        match profile {
+            Some(p) => {
+                ctx.db.application_inventor().insert(ApplicationInventor {
+                    row_id: 0,
+                    application_id: id,
+                    inventor: p.identity,
+                    position: inv.sequence,
+                    contribution: String::new(),
+                    contribution_percent: None,
+                    declaration_status: DeclarationStatus::Pending,
+                    dispute_reason: String::new(),
+                    updated_at: ctx.timestamp,
+                });
+            }
+            None => spacetimedb::log::warn!("Imported inventor '{}' has no registered profile; skipped", inv.name),
+        }
+    }
+
+    for c in parsed.citations {
+        let result = ctx.db.prior_art_result().insert(PriorArtResult {
+            result_id: 0,
+            application_id: id,
+            source: c.source,
+            url: c.url,
+            summary: c.text,
+            relevance_score: 0.0,
+            found_at: ctx.timestamp,
+        });
+        let citation = ensure_citation(ctx, &result);
+        if !c.publication_number.is_empty() && citation.publication_number != c.publication_number {
+            let mut citation = citation;
+            citation.kind = reference_kind(&c.publication_number);
+            citation.dedupe_key = citation_dedupe_key(&c.publication_number, &result);
+            citation.publication_number = c.publication_number;
+            ctx.db.prior_art_citation().result_id().update(citation);
+        }
+    }
+    recompute_prior_art_progress(ctx, id);
+    refresh_filing_readiness(ctx, id);
+    spacetimedb::log::info!("Imported application app_id={} from ST.96 XML", id);
+    Ok(())
+}
+
+// ---------- Portfolio Import ----------
+
+const MAX_IMPORT_ROWS: usize = 1000;
+
+// One application in an import payload. JSON rows use these keys; CSV files use them as headers.
+#[derive(serde::Deserialize, Default)]
+#[serde(default)]
+struct ImportRow {
+    title: String,
+    #[serde(alias = "abstract")]
+    abstract_text: String,
+    #[serde(alias = "claims")]
+    claims_text: String,
+    status: String,
+    role: Option<String>,
+    created_at: Option<String>,
+    last_updated: Option<String>,
+    office: Option<String>,
+    application_number: Option<String>,
+    filing_date: Option<String>,
+    patent_number: Option<String>,
+    grant_date: Option<String>,
+    maintenance_paid: Option<String>, // payment dates in window order separated by ';', blank if unknown
+}
+
+// A row that passed validation, ready to insert
+struct ValidatedImport {
+    title: String,
+    abstract_text: String,
+    claims_text: String,
+    status: PatentStatus,
+    created_at: Timestamp,
+    last_updated: Timestamp,
+    filing: Option<(PatentOffice, String, Timestamp)>,
+    grant: Option<ImportedGrant>,
+}
+
+struct ImportedGrant {
+    patent_number: String,
+    grant_date: Timestamp,
+    fees: Vec<MaintenanceFee>, // application_id is filled in on insert
+}
+
+// Builds the maintenance schedule of an imported grant from the payment dates in the payload. Every
+// window whose grace period closed before the import needs a date, since without one we can't tell
+// a patent in force from a lapsed one; windows still open stay unpaid.
+fn imported_maintenance_fees(grant_date: Timestamp, paid: Option<&str>, now: Timestamp) -> Result<Vec<MaintenanceFee>, String> {
+    let mut fees = maintenance_schedule(0, grant_date);
+    let dates: Vec<&str> = paid.map(|p| p.split(';').map(str::trim).collect()).unwrap_or_default();
+    if dates.len() > fees.len() {
+        return Err(format!("maintenance_paid lists {} payments but there are {} windows", dates.len(), fees.len()));
+    }
+    for (i, fee) in fees.iter_mut().enumerate() {
+        match dates.get(i).filter(|d| !d.is_empty()) {
+            Some(d) => {
+                let at = parse_date(d).map_err(|e| format!("maintenance_paid: {}", e))?;
+                if at > now || at < fee.window_opens_at || at > fee.grace_ends_at {
+                    return Err(format!("maintenance_paid: {} is outside the {} payment window", d, fee.label));
+                }
+                fee.paid_at = Some(at);
+            }
+            None if now > fee.grace_ends_at => {
+                return Err(format!("maintenance_paid: the {} window has closed; its payment date is required", fee.label))
+            }
+            None => {}
+        }
+    }
+    Ok(fees)
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+fn parse_status(text: &str) -> Result<PatentStatus, String> {
+    match text.trim().to_lowercase().as_str() {
+        "draft" => Ok(PatentStatus::Draft),
+        "submitted" | "filed" | "pending" => Ok(PatentStatus::Submitted),
+        "examination" => Ok(PatentStatus::Examination),
+        "granted" => Ok(PatentStatus::Granted),
+        "rejected" => Ok(PatentStatus::Rejected),
+        "abandoned" => Ok(PatentStatus::Abandoned),
+        _ => Err(format!("Unknown status '{}'", text)),
+    }
+}
+
+fn parse_role(text: &str) -> Result<PortfolioRole, String> {
+    match text.trim().to_lowercase().replace(['-', '_', ' '], "").as_str() {
+        "owner" => Ok(PortfolioRole::Owner),
+        "coowner" => Ok(PortfolioRole::CoOwner),
+        "licensee" => Ok(PortfolioRole::Licensee),
+        "viewer" => Ok(PortfolioRole::Viewer),
+        _ => Err(format!("Unknown role '{}'", text)),
+    }
+}
+
+// Accepts office names or their two-letter codes
+fn parse_office(text: &str) -> Result<PatentOffice, String> {
+    match text.trim().to_uppercase().as_str() {
+        "USPTO" | "US" => Ok(PatentOffice::Uspto),
+        "EPO" | "EP" => Ok(PatentOffice::Epo),
+        "JPO" | "JP" => Ok(PatentOffice::Jpo),
+        "CNIPA" | "CN" => Ok(PatentOffice::Cnipa),
+        "WIPO" | "WO" => Ok(PatentOffice::Wipo),
+        _ => Err(format!("Unknown patent office '{}'", text)),
+    }
+}
+
+// Splits the payload into rows; a row that fails to deserialize becomes that row's error
+fn parse_import_rows(format: &ImportFormat, payload: &str) -> Result<Vec<Result<ImportRow, String>>, String> {
+    match format {
+        ImportFormat::Json => {
+            let values: Vec<serde_json::Value> =
+                serde_json::from_str(payload).map_err(|e| format!("Payload must be a JSON array of objects: {}", e))?;
+            Ok(values
+                .into_iter()
+                .map(|v| serde_json::from_value(v).map_err(|e| e.to_string()))
+                .collect())
+        }
+        ImportFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new().trim(csv::Trim::Headers).from_reader(payload.as_bytes());
+            Ok(reader.deserialize().map(|r| r.map_err(|e: csv::Error| e.to_string())).collect())
+        }
+    }
+}
+
+fn validate_import_row(row: ImportRow, now: Timestamp) -> Result<ValidatedImport, String> {
+    let title = row.title.trim().to_string();
+    if title.is_empty() {
+        return Err("title is required".into());
+    }
+    let status = parse_status(&row.status)?;
+    // The importer becomes the owner of every imported application, so that is the only role a row
+    // may ask for; co-ownership and other roles are granted by the owner afterwards
+    if let Some(r) = non_empty(&row.role) {
+        let role = parse_role(r)?;
+        if role != PortfolioRole::Owner {
+            return Err(format!("role {:?} cannot be imported; only owned applications can be imported", role));
+        }
+    }
+    let date = |value: &Option<String>, field: &str| -> Result<Option<Timestamp>, String> {
+        match non_empty(value) {
+            Some(v) => {
+                let at = parse_date(v).map_err(|e| format!("{}: {}", field, e))?;
+                if at > now {
+                    return Err(format!("{} is in the future", field));
+                }
+                Ok(Some(at))
+            }
+            None => Ok(None),
+        }
+    };
+    let filing_date = date(&row.filing_date, "filing_date")?;
+    let grant_date = date(&row.grant_date, "grant_date")?;
+
+    let filing = match (non_empty(&row.application_number), filing_date) {
+        (Some(number), Some(filed)) => {
+            let office = match non_empty(&row.office) {
+                Some(o) => parse_office(o)?,
+                None => PatentOffice::Uspto,
+            };
+            Some((office, number.to_string(), filed))
+        }
+        (Some(_), None) => return Err("application_number requires filing_date".into()),
+        (None, _) if non_empty(&row.office).is_some() => return Err("office requires application_number".into()),
+        (None, _) => None,
+    };
+    let grant = match (non_empty(&row.patent_number), grant_date) {
+        (Some(_), _) if status != PatentStatus::Granted => return Err("patent_number is only allowed for granted patents".into()),
+        (Some(number), Some(granted)) => Some(ImportedGrant {
+            patent_number: number.to_string(),
+            grant_date: granted,
+            fees: imported_maintenance_fees(granted, non_empty(&row.maintenance_paid), now)?,
+        }),
+        (Some(_), None) => return Err("patent_number requires grant_date".into()),
+        (None, Some(_)) => return Err("grant_date requires patent_number".into()),
+        (None, None) if non_empty(&row.maintenance_paid).is_some() => return Err("maintenance_paid requires patent_number".into()),
+        (None, None) => None,
+    };
+    if let (Some(filed), Some(granted)) = (filing_date, grant_date) {
+        if granted < filed {
+            return Err("grant_date is before filing_date".into());
+        }
+    }
+
+    let known: Vec<Timestamp> = [filing_date, grant_date].into_iter().flatten().collect();
+    let created_at = match date(&row.created_at, "created_at")? {
+        Some(at) => at,
+        None => known.iter().min().copied().unwrap_or(now),
+    };
+    let last_updated = match date(&row.last_updated, "last_updated")? {
+        Some(at) => at,
+        None => known.iter().chain([&created_at]).max().copied().unwrap_or(created_at),
+    };
+    if last_updated < created_at {
+        return Err("last_updated is before created_at".into());
+    }
+
+    Ok(ValidatedImport {
+        title,
+        abstract_text: row.abstract_text,
+        claims_text: row.claims_text,
+        status,
+        created_at,
+        last_updated,
+        filing,
+        grant,
+    })
+}
+
+// Stage rows an application with this history would have, with the date each stage was entered
+fn imported_stages(row: &ValidatedImport) -> Vec<(Stage, u8, Timestamp)> {
+    let filed = row.filing.as_ref().map(|(_, _, at)| *at).unwrap_or(row.last_updated);
+    let granted = row.grant.as_ref().map(|g| g.grant_date).unwrap_or(row.last_updated);
+    let mut stages = match row.status {
+        PatentStatus::Draft => vec![(Stage::Drafting, 0, row.created_at)],
+        PatentStatus::Submitted => vec![(Stage::Filing, 100, filed)],
+        PatentStatus::Examination => vec![(Stage::Filing, 100, filed), (Stage::Examination, 0, row.last_updated)],
+        PatentStatus::Granted => vec![
+            (Stage::Filing, 100, filed),
+            (Stage::Examination, 100, filed),
+            (Stage::Grant, 100, granted),
+        ],
+        PatentStatus::Rejected | PatentStatus::Abandoned => match row.filing {
+            Some(_) => vec![(Stage::Filing, 100, filed)],
+            None => Vec::new(),
+        },
+    };
+    if let Some(g) = &row.grant {
+        let paid: Vec<Timestamp> = g.fees.iter().filter_map(|f| f.paid_at).collect();
+        if let Some(first) = paid.iter().min() {
+            stages.push((Stage::Maintenance, (paid.len() * 100 / MAINTENANCE_WINDOWS.len()) as u8, *first));
+        }
+    }
+    stages
+}
+
+fn insert_imported_application(ctx: &ReducerContext, row: ValidatedImport, report_id: u64) -> u64 {
+    let app = ctx.db.patent_application().insert(PatentApplication {
+        application_id: 0,
+        owner: ctx.sender,
+        title: row.title.clone(),
+        abstract_text: row.abstract_text.clone(),
+        claims_text: row.claims_text.clone(),
+        status: row.status.clone(),
+        created_at: row.created_at,
+        last_updated: row.last_updated,
+    });
+    let id = app.application_id;
+    ctx.db.portfolio_entry().insert(PatentPortfolioEntry {
+        entry_id: 0,
+        owner: ctx.sender,
+        application_id: id,
+        role: PortfolioRole::Owner,
+        added_at: row.created_at,
+    });
+    for (stage, percent, entered_at) in imported_stages(&row) {
+        ctx.db.stage_progress().insert(StageProgress {
+            progress_id: 0,
+            application_id: id,
+            stage: stage.clone(),
+            percent,
+            updated_at: entered_at,
+        });
+        ctx.db.stage_transition().insert(StageTransition { transition_id: 0, application_id: id, stage, entered_at });
+    }
+    let filed = row.filing.as_ref().map(|(_, _, at)| *at);
+    if let Some((office, application_number, filing_date)) = row.filing {
+        ctx.db.jurisdiction_filing().insert(JurisdictionFiling {
+            filing_id: 0,
+            application_id: id,
+            office,
+            application_number,
+            filing_date,
+            status: row.status.clone(),
+            created_at: ctx.timestamp,
+            updated_at: ctx.timestamp,
+        });
+    }
+    if let Some(grant) = row.grant {
+        let term_start = filed.unwrap_or(grant.grant_date);
+        ctx.db.grant_record().insert(GrantRecord {
+            application_id: id,
+            patent_number: grant.patent_number,
+            grant_date: grant.grant_date,
+            expiry_date: add_months(term_start, 240),
+            lapsed: false,
+            lapsed_at: None,
+            recorded_at: ctx.timestamp,
+        });
+        for fee in grant.fees {
+            ctx.db.maintenance_fee().insert(MaintenanceFee { application_id: id, ..fee });
+        }
+    }
+    record_event(ctx, id, ApplicationEventPayload::Imported(report_id));
+    recompute_deadlines(ctx, id);
+    refresh_filing_readiness(ctx, id);
+    id
+}
+
+// Imports existing applications with their original status, dates and numbers. Every row is validated
+// first; if any row fails nothing is created and the errors are listed on the import_report row.
+// Statuses record filings that already happened at the office, so the inventorship gate is not
+// applied here; it still applies to every filing step taken afterwards (filing details, grants,
+// new jurisdictions, status changes).
+#[reducer]
+pub fn import_portfolio(ctx: &ReducerContext, format: ImportFormat, payload: String) -> Result<(), String> {
+    let rows = parse_import_rows(&format, &payload)?;
+    if rows.is_empty() {
+        return Err("Import payload contains no rows".into());
+    }
+    if rows.len() > MAX_IMPORT_ROWS {
+        return Err(format!("Import is limited to {} rows per call", MAX_IMPORT_ROWS));
+    }
+
+    let mut errors: Vec<ImportRowError> = Vec::new();
+    let mut valid: Vec<ValidatedImport> = Vec::new();
+    let mut seen_application_numbers: Vec<(PatentOffice, String)> = ctx
+        .db
+        .jurisdiction_filing()
+        .iter()
+        .map(|f| (f.office, f.application_number.to_uppercase()))
+        .collect();
+    let mut seen_patent_numbers: Vec<String> = ctx.db.grant_record().iter().map(|g| g.patent_number.to_uppercase()).collect();
+    let total_rows = rows.len() as u32;
+    for (index, parsed) in rows.into_iter().enumerate() {
+        let row = (index + 1) as u32;
+        let checked = parsed.and_then(|r| validate_import_row(r, ctx.timestamp)).and_then(|v| {
+            if let Some((office, number, _)) = &v.filing {
+                let key = (office.clone(), number.to_uppercase());
+                if seen_application_numbers.contains(&key) {
+                    return Err(format!("Application number {} is already recorded for {:?}", number, office));
+                }
+                seen_application_numbers.push(key);
+            }
+            if let Some(g) = &v.grant {
+                if seen_patent_numbers.contains(&g.patent_number.to_uppercase()) {
+                    return Err(format!("Patent number {} is already recorded", g.patent_number));
+                }
+                seen_patent_numbers.push(g.patent_number.to_uppercase());
+            }
+            Ok(v)
+        });
+        match checked {
+            Ok(v) => valid.push(v),
+            Err(message) => errors.push(ImportRowError { row, message }),
+        }
+    }
+
+    let committed = errors.is_empty();
+    let report = ctx.db.import_report().insert(ImportReport {
+        report_id: 0,
+        owner: ctx.sender,
+        format,
+        total_rows,
+        committed,
+        application_ids: Vec::new(),
+        errors,
+        created_at: ctx.timestamp,
+    });
+    if !committed {
+        spacetimedb::log::info!("Import {} rejected: {} of {} rows invalid", report.report_id, report.errors.len(), total_rows);
+        return Ok(());
+    }
+
+    let report_id = report.report_id;
+    let application_ids = valid.into_iter().map(|v| insert_imported_application(ctx, v, report_id)).collect();
+    ctx.db.import_report().report_id().update(ImportReport { application_ids, ..report });
+    spacetimedb::log::info!("Import {} created {} applications for {}", report_id, total_rows, ctx.sender);
+    Ok(())
+}
+
+// ---------- Portfolio Export ----------
+
+// Bump when the archive layout changes so importers can tell versions apart
+const PORTFOLIO_EXPORT_SCHEMA_VERSION: &str = "inventavault.portfolio/1";
+
+fn json_time(at: Timestamp) -> Value {
+    at.to_rfc3339()
+        .map(Value::from)
+        .unwrap_or_else(|_| Value::from(at.to_micros_since_unix_epoch()))
+}
+
+fn json_opt_time(at: Option<Timestamp>) -> Value {
+    at.map(json_time).unwrap_or(Value::Null)
+}
+
+fn application_archive(ctx: &ReducerContext, app: &PatentApplication, roles: Vec<String>) -> Value {
+    let id = app.application_id;
+    let prior_art: Vec<Value> = ctx
+        .db
+        .prior_art_result()
+        .application_id()
+        .filter(id)
+        .map(|r| {
+            let citation = ctx.db.prior_art_citation().result_id().find(r.result_id);
+            json!({
+                "result_id": r.result_id,
+                "source": r.source,
+                "url": r.url,
+                "summary": r.summary,
+                "relevance_score": r.relevance_score,
+                "found_at": json_time(r.found_at),
+                "reviewed": ctx.db.prior_art_review().result_id().find(r.result_id).is_some(),
+                "publication_number": citation.as_ref().map(|c| c.publication_number.clone()),
+                "publication_date": json_opt_time(citation.and_then(|c| c.publication_date)),
+            })
+        })
+        .collect();
+    let documents: Vec<Value> = ctx
+        .db
+        .document_generation()
+        .application_id()
+        .filter(id)
+        .map(|d| {
+            json!({
+                "doc_type": format!("{:?}", d.doc_type),
+                "status": format!("{:?}", d.status),
+                "error_message": d.error_message,
+                "updated_at": json_time(d.updated_at),
+            })
+        })
+        .collect();
+    let mut versions: Vec<DocumentVersion> = ctx.db.document_version().application_id().filter(id).collect();
+    versions.sort_by_key(|v| v.version_id);
+    let document_versions: Vec<Value> = versions
+        .into_iter()
+        .map(|v| {
+            json!({
+                "version_id": v.version_id,
+                "doc_type": format!("{:?}", v.doc_type),
+                "version_number": v.version_number,
+                "content": v.content,
+                "content_hash": v.content_hash,
+                "generator": v.generator,
+                "author": v.author.to_string(),
+                "parent_version_id": v.parent_version_id,
+                "filed": v.filed,
+                "created_at": json_time(v.created_at),
+            })
+        })
+        .collect();
+    let blockchain_records: Vec<Value> = ctx
+        .db
+        .blockchain_record()
+        .application_id()
+        .filter(id)
+        .map(|b| {
+            json!({
+                "record_id": b.record_id,
+                "tx_hash": b.tx_hash,
+                "network": b.network,
+                "recorded_at": json_time(b.recorded_at),
+            })
+        })
+        .collect();
+    let alerts: Vec<Value> = ctx
+        .db
+        .infringement_alert()
+        .application_id()
+        .filter(id)
+        .map(|a| {
+            json!({
+                "alert_id": a.alert_id,
+                "alert_type": a.alert_type,
+                "severity": format!("{:?}", a.severity),
+                "description": a.description,
+                "detected_at": json_time(a.detected_at),
+                "resolved": a.resolved,
+            })
+        })
+        .collect();
+    let stage_progress: Vec<Value> = ctx
+        .db
+        .stage_progress()
+        .application_id()
+        .filter(id)
+        .map(|sp| {
+            json!({
+                "stage": format!("{:?}", sp.stage),
+                "percent": sp.percent,
+                "updated_at": json_time(sp.updated_at),
+            })
+        })
+        .collect();
+
+    json!({
+        "application_id": id,
+        "owner": app.owner.to_string(),
+        "roles": roles,
+        "title": app.title,
+        "abstract": app.abstract_text,
+        "claims_text": app.claims_text,
+        "claims": split_claims(&app.claims_text),
+        "status": format!("{:?}", app.status),
+        "created_at": json_time(app.created_at),
+        "last_updated": json_time(app.last_updated),
+        "prior_art": prior_art,
+        "documents": documents,
+        "document_versions": document_versions,
+        "blockchain_records": blockchain_records,
+        "alerts": alerts,
+        "stage_progress": stage_progress,
+    })
+}
+
+// Filing, prosecution, ownership and notarization records of an application, keyed by archive field
+fn application_records(ctx: &ReducerContext, id: u64) -> Vec<(&'static str, Value)> {
+    let filing_detail = ctx.db.filing_detail().application_id().find(id).map(|f| {
+        json!({
+            "filing_type": format!("{:?}", f.filing_type),
+            "filing_date": json_time(f.filing_date),
+            "priority_date": json_opt_time(f.priority_date),
+        })
+    });
+    let jurisdiction_filings: Vec<Value> = ctx
+        .db
+        .jurisdiction_filing()
+        .application_id()
+        .filter(id)
+        .map(|f| {
+            json!({
+                "office": format!("{:?}", f.office),
+                "application_number": f.application_number,
+                "filing_date": json_time(f.filing_date),
+                "status": format!("{:?}", f.status),
+                "updated_at": json_time(f.updated_at),
+            })
+        })
+        .collect();
+    let mut inventor_rows: Vec<ApplicationInventor> = ctx.db.application_inventor().application_id().filter(id).collect();
+    inventor_rows.sort_by_key(|i| i.position);
+    let inventors: Vec<Value> = inventor_rows
+        .into_iter()
+        .map(|i| {
+            json!({
+                "inventor": i.inventor.to_string(),
+                "position": i.position,
+                "contribution": i.contribution,
+                "contribution_percent": i.contribution_percent,
+                "declaration_status": format!("{:?}", i.declaration_status),
+                "dispute_reason": i.dispute_reason,
+                "updated_at": json_time(i.updated_at),
+            })
+        })
+        .collect();
+    let co_owners: Vec<Value> = ctx
+        .db
+        .co_owner_grant()
+        .application_id()
+        .filter(id)
+        .map(|c| json!({ "holder": c.holder.to_string(), "granted_at": json_time(c.granted_at) }))
+        .collect();
+    let classifications: Vec<Value> = ctx
+        .db
+        .classification()
+        .application_id()
+        .filter(id)
+        .map(|c| {
+            json!({
+                "scheme": format!("{:?}", c.scheme),
+                "code": c.code,
+                "primary": c.primary,
+                "source": format!("{:?}", c.source),
+                "score": c.score,
+            })
+        })
+        .collect();
+    let office_actions: Vec<Value> = ctx
+        .db
+        .office_action()
+        .application_id()
+        .filter(id)
+        .map(|oa| {
+            json!({
+                "action_id": oa.action_id,
+                "action_type": format!("{:?}", oa.action_type),
+                "mailed_at": json_time(oa.mailed_at),
+                "shortened_deadline": json_time(oa.shortened_deadline),
+                "statutory_deadline": json_time(oa.statutory_deadline),
+                "extension_months": oa.extension_months,
+                "extension_fee_cents": oa.extension_fee_cents,
+                "response_filed_at": json_opt_time(oa.response_filed_at),
+            })
+        })
+        .collect();
+    let deadlines: Vec<Value> = ctx
+        .db
+        .deadline()
+        .application_id()
+        .filter(id)
+        .map(|d| {
+            json!({
+                "kind": format!("{:?}", d.kind),
+                "source_id": d.source_id,
+                "label": d.label,
+                "due_at": json_time(d.due_at),
+                "completed": d.completed,
+            })
+        })
+        .collect();
+    let grant = ctx.db.grant_record().application_id().find(id).map(|g| {
+        json!({
+            "patent_number": g.patent_number,
+            "grant_date": json_time(g.grant_date),
+            "expiry_date": json_time(g.expiry_date),
+            "lapsed": g.lapsed,
+            "lapsed_at": json_opt_time(g.lapsed_at),
+        })
+    });
+    let maintenance_fees: Vec<Value> = ctx
+        .db
+        .maintenance_fee()
+        .application_id()
+        .filter(id)
+        .map(|f| {
+            json!({
+                "label": f.label,
+                "window_opens_at": json_time(f.window_opens_at),
+                "due_at": json_time(f.due_at),
+                "grace_ends_at": json_time(f.grace_ends_at),
+                "paid_at": json_opt_time(f.paid_at),
+                "fee_cents": f.fee_cents,
+                "surcharge_cents": f.surcharge_cents,
+            })
+        })
+        .collect();
+    let family_links: Vec<Value> = ctx
+        .db
+        .family_link()
+        .parent_application_id()
+        .filter(id)
+        .chain(ctx.db.family_link().child_application_id().filter(id))
+        .map(|l| {
+            json!({
+                "link_id": l.link_id,
+                "parent_application_id": l.parent_application_id,
+                "child_application_id": l.child_application_id,
+                "relationship": format!("{:?}", l.relationship),
+                "priority_date": json_time(l.priority_date),
+            })
+        })
+        .collect();
+    let notarizations: Vec<Value> = ctx
+        .db
+        .notarization()
+        .application_id()
+        .filter(id)
+        .map(|n| {
+            let batch = n.batch_id.and_then(|b| ctx.db.merkle_batch().batch_id().find(b));
+            let proof = batch
+                .as_ref()
+                .and_then(|b| b.leaves.iter().find(|l| l.notarization_id == n.notarization_id))
+                .map(|l| {
+                    l.proof
+                        .iter()
+                        .map(|step| json!({ "sibling": step.sibling, "sibling_is_left": step.sibling_is_left }))
+                        .collect::<Vec<Value>>()
+                });
+            json!({
+                "notarization_id": n.notarization_id,
+                "scheme": n.scheme,
+                "digest": n.digest,
+                "version_ids": n.version_ids,
+                "created_at": json_time(n.created_at),
+                "record_id": n.record_id,
+                "batch_id": n.batch_id,
+                "merkle_root": batch.as_ref().map(|b| b.root.clone()),
+                "merkle_proof": proof,
+            })
+        })
+        .collect();
+
+    vec![
+        ("filing_detail", filing_detail.unwrap_or(Value::Null)),
+        ("jurisdiction_filings", Value::from(jurisdiction_filings)),
+        ("inventors", Value::from(inventors)),
+        ("co_owners", Value::from(co_owners)),
+        ("classifications", Value::from(classifications)),
+        ("office_actions", Value::from(office_actions)),
+        ("deadlines", Value::from(deadlines)),
+        ("grant", grant.unwrap_or(Value::Null)),
+        ("maintenance_fees", Value::from(maintenance_fees)),
+        ("family_links", Value::from(family_links)),
+        ("notarizations", Value::from(notarizations)),
+    ]
+}
+
+// Per-application data that is derived or operational and can be recomputed after a restore;
+// listed in the archive so readers know it was left out on purpose
+const PORTFOLIO_EXPORT_EXCLUDED: &[&str] = &[
+    "application_event",
+    "deadline_reminder",
+    "doc_job",
+    "doc_gen_attempt",
+    "document_diff",
+    "filing_readiness",
+    "ids_filing",
+    "prior_art_citation (kept only as publication_number/publication_date on prior_art)",
+    "stage_transition",
+    "analytics snapshots and rollups",
+];
+
+// Everything the identity owns, co-owns or is named as inventor on, as one JSON document; other
+// portfolio entries are listed by id and role only
+// Applications to archive with the caller's roles on each. Only ownership, a co-owner grant or
+// being a named inventor puts an application in the archive; self-assigned portfolio entries
+// merely annotate applications that are already in it
+fn archive_application_roles(
+    owned: &[u64],
+    co_owned: &[u64],
+    named_inventor: &[u64],
+    entries: &[(u64, PortfolioRole)],
+) -> Vec<(u64, Vec<String>)> {
+    let mut roles: Vec<(u64, Vec<String>)> = Vec::new();
+    let verified = [(owned, "Owner"), (co_owned, "CoOwner"), (named_inventor, "Inventor")];
+    for (ids, role) in verified {
+        for id in ids {
+            match roles.iter_mut().find(|(a, _)| a == id) {
+                Some((_, r)) => r.push(role.to_string()),
+                None => roles.push((*id, vec![role.to_string()])),
+            }
+        }
+    }
+    for (id, role) in entries {
+        if let Some((_, r)) = roles.iter_mut().find(|(a, _)| a == id) {
+            r.push(format!("Portfolio:{:?}", role));
+        }
+    }
+    roles.sort_by_key(|(id, _)| *id);
+    roles
+}
+
+fn portfolio_archive(ctx: &ReducerContext, who: Identity) -> Value {
+    let owned: Vec<u64> = ctx.db.patent_application().owner().filter(who).map(|a| a.application_id).collect();
+    let co_owned: Vec<u64> = ctx.db.co_owner_grant().holder().filter(who).map(|c| c.application_id).collect();
+    let named_inventor: Vec<u64> = ctx.db.application_inventor().inventor().filter(who).map(|i| i.application_id).collect();
+    let entries: Vec<PatentPortfolioEntry> = ctx.db.portfolio_entry().owner().filter(who).collect();
+    let entry_roles: Vec<(u64, PortfolioRole)> = entries.iter().map(|e| (e.application_id, e.role.clone())).collect();
+    let roles = archive_application_roles(&owned, &co_owned, &named_inventor, &entry_roles);
+
+    let applications: Vec<Value> = roles
+        .into_iter()
+        .filter_map(|(id, r)| {
+            let app = ctx.db.patent_application().application_id().find(id)?;
+            let mut archive = application_archive(ctx, &app, r);
+            if let Some(fields) = archive.as_object_mut() {
+                for (key, value) in application_records(ctx, id) {
+                    fields.insert(key.to_string(), value);
+                }
+            }
+            Some(archive)
+        })
+        .collect();
+    let portfolio_entries: Vec<Value> = entries
+        .iter()
+        .map(|e| {
+            json!({
+                "entry_id": e.entry_id,
+                "application_id": e.application_id,
+                "role": format!("{:?}", e.role),
+                "added_at": json_time(e.added_at),
+            })
+        })
+        .collect();
+    let profile = ctx.db.inventor().identity().find(who).map(|p| {
+        json!({
+            "name": p.name,
+            "email": p.email,
+            "affiliation": p.affiliation,
+            "skills": p.skills,
+            "bio": p.bio,
+            "created_at": json_time(p.created_at),
+            "updated_at": json_time(p.updated_at),
+        })
+    });
+
+    json!({
+        "schema_version": PORTFOLIO_EXPORT_SCHEMA_VERSION,
+        "exported_at": json_time(ctx.timestamp),
+        "identity": who.to_string(),
+        "inventor": profile,
+        "applications": applications,
+        "portfolio_entries": portfolio_entries,
+        "excluded_tables": PORTFOLIO_EXPORT_EXCLUDED,
+    })
+}
+
+// Writes the caller's full portfolio archive to export_artifact; content_hash covers the exact bytes stored
+#[reducer]
+pub fn export_portfolio(ctx: &ReducerContext) -> Result<(), String> {
+    let archive = portfolio_archive(ctx, ctx.sender);
+    let content = serde_json::to_string_pretty(&archive).map_err(|e| format!("Failed to serialize portfolio: {}", e))?;
+    let artifact = ctx.db.export_artifact().insert(ExportArtifact {
+        artifact_id: 0,
+        owner: ctx.sender,
+        application_id: None,
+        format: ExportFormat::PortfolioJson,
+        schema_version: PORTFOLIO_EXPORT_SCHEMA_VERSION.to_string(),
+        file_name: format!("portfolio-{}.json", format_date(ctx.timestamp)),
+        content_type: "application/json".to_string(),
+        content_hash: sha256_hex(content.as_bytes()),
+        content,
+        created_at: ctx.timestamp,
+    });
+    spacetimedb::log::info!("Portfolio export {} created for {}", artifact.artifact_id, ctx.sender);
+    Ok(())
+}
+
+// ---------- Notarization ----------
+
+const NOTARIZATION_SCHEME: &str = "inventavault.notarization/1";
+
+// Each field is a tag and a big-endian length followed by the UTF-8 bytes, so no two
+// different revisions can serialize to the same bytes
+fn push_canonical_field(out: &mut Vec<u8>, tag: &str, value: &str) {
+    for part in [tag, value] {
+        out.extend_from_slice(&(part.len() as u64).to_be_bytes());
+        out.extend_from_slice(part.as_bytes());
+    }
+}
+
+// Deterministic serialization of the application text and the latest version of each document,
+// ordered by document type. Returns the bytes and the document versions they cover.
+fn canonical_application_bytes(ctx: &ReducerContext, app: &PatentApplication) -> (Vec<u8>, Vec<u64>) {
+    let mut out = Vec::new();
+    push_canonical_field(&mut out, "scheme", NOTARIZATION_SCHEME);
+    push_canonical_field(&mut out, "application_id", &app.application_id.to_string());
+    push_canonical_field(&mut out, "title", &app.title);
+    push_canonical_field(&mut out, "abstract", &app.abstract_text);
+    push_canonical_field(&mut out, "claims", &app.claims_text);
+
+    let mut latest: Vec<DocumentVersion> = Vec::new();
+    for v in ctx.db.document_version().application_id().filter(app.application_id) {
+        match latest.iter_mut().find(|l| l.doc_type == v.doc_type) {
+            Some(l) if l.version_number >= v.version_number => {}
+            Some(l) => *l = v,
+            None => latest.push(v),
+        }
+    }
+    latest.sort_by_key(|v| format!("{:?}", v.doc_type));
+    for v in &latest {
+        push_canonical_field(&mut out, "document", &format!("{:?}", v.doc_type));
+        push_canonical_field(&mut out, "version", &v.version_number.to_string());
+        push_canonical_field(&mut out, "content_sha256", &v.content_hash);
+    }
+    (out, latest.iter().map(|v| v.version_id).collect())
+}
+
+fn application_digest(ctx: &ReducerContext, app: &PatentApplication) -> (String, Vec<u64>) {
+    let (bytes, version_ids) = canonical_application_bytes(ctx, app);
+    (sha256_hex(&bytes), version_ids)
+}
+
+// Computes the digest of the current revision; the client anchors `digest` and reports the transaction back
+#[reducer]
+pub fn notarize_application(ctx: &ReducerContext, application_id: u64) -> Result<(), String> {
+    let app = require_owner(ctx, application_id)?;
+    let (digest, version_ids) = application_digest(ctx, &app);
+    let unchanged = ctx
+        .db
+        .notarization()
+        .application_id()
+        .filter(application_id)
+        .max_by_key(|n| n.notarization_id)
+        .filter(|n| n.digest == digest);
+    if let Some(n) = unchanged {
+        return Err(format!("Application is unchanged since notarization {}", n.notarization_id));
+    }
+    let row = ctx.db.notarization().insert(Notarization {
+        notarization_id: 0,
+        application_id,
+        scheme: NOTARIZATION_SCHEME.to_string(),
+        digest,
+        version_ids,
+        requested_by: ctx.sender,
+        created_at: ctx.timestamp,
+        record_id: None,
+        batch_requested: false,
+        batch_id: None,
+    });
+    spacetimedb::log::info!("Notarization {} for app_id={} digest={}", row.notarization_id, application_id, row.digest);
+    Ok(())
+}
+
+// Records the transaction that anchored a notarization digest
+#[reducer]
+pub fn anchor_notarization(ctx: &ReducerContext, notarization_id: u64, tx_hash: String, network: String) -> Result<(), String> {
+    let mut n = ctx.db.notarization().notarization_id().find(notarization_id).ok_or("Notarization not found")?;
+    require_owner(ctx, n.application_id)?;
+    if n.record_id.is_some() {
+        return Err("Notarization is already anchored".into());
+    }
+    if let Some(batch_id) = n.batch_id {
+        return Err(format!("Notarization is part of Merkle batch {}", batch_id));
+    }
+    let tx_hash = tx_hash.trim().to_string();
+    if tx_hash.is_empty() {
+        return Err("Transaction hash is required".into());
+    }
+    let record = insert_blockchain_record(ctx, n.application_id, tx_hash, network);
+    n.record_id = Some(record.record_id);
+    n.batch_requested = false;
+    ctx.db.notarization().notarization_id().update(n);
+    Ok(())
+}
+
+// ---------- Merkle Batching ----------
+
+const MAX_BATCH_LEAVES: usize = 1024;
+
+fn hex_decode(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok()).collect()
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// Leaves and inner nodes are hashed with different prefixes so a leaf can't pose as a node
+fn merkle_leaf_hash(digest_hex: &str) -> Option<[u8; 32]> {
+    let digest = hex_decode(digest_hex)?;
+    let mut hasher = Sha256::new();
+    hasher.update([0u8]);
+    hasher.update(&digest);
+    Some(hasher.finalize().into())
+}
+
+fn merkle_node_hash(left: &[u8], right: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([1u8]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+// Returns the root and each leaf's proof. An unpaired node at the end of a level moves up unchanged,
+// so its proof has no step for that level.
+fn build_merkle_tree(leaves: &[[u8; 32]]) -> ([u8; 32], Vec<Vec<MerkleProofStep>>) {
+    let mut proofs: Vec<Vec<MerkleProofStep>> = vec![Vec::new(); leaves.len()];
+    // positions[i] is the index of leaf i's ancestor within the current level
+    let mut positions: Vec<usize> = (0..leaves.len()).collect();
+    let mut level: Vec<[u8; 32]> = leaves.to_vec();
+    while level.len() > 1 {
+        for (leaf, pos) in positions.iter_mut().enumerate() {
+            let sibling = *pos ^ 1;
+            if sibling < level.len() {
+                proofs[leaf].push(MerkleProofStep { sibling: hex_encode(&level[sibling]), sibling_is_left: sibling < *pos });
+            }
+            *pos /= 2;
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| if pair.len() == 2 { merkle_node_hash(&pair[0], &pair[1]) } else { pair[0] })
+            .collect();
+    }
+    (level.first().copied().unwrap_or([0u8; 32]), proofs)
+}
+
+// Folds a proof from the leaf hash up to the root it commits to
+fn merkle_root_from_proof(leaf_hash: [u8; 32], proof: &[MerkleProofStep]) -> Option<[u8; 32]> {
+    let mut node = leaf_hash;
+    for step in proof {
+        let sibling = hex_decode(&step.sibling)?;
+        node = if step.sibling_is_left { merkle_node_hash(&sibling, &node) } else { merkle_node_hash(&node, &sibling) };
+    }
+    Some(node)
+}
+
+// Opts a notarization in to the next Merkle batch instead of anchoring it individually
+#[reducer]
+pub fn request_batch_anchoring(ctx: &ReducerContext, notarization_id: u64) -> Result<(), String> {
+    let mut n = ctx.db.notarization().notarization_id().find(notarization_id).ok_or("Notarization not found")?;
+    require_owner(ctx, n.application_id)?;
+    if n.record_id.is_some() {
+        return Err("Notarization is already anchored".into());
+    }
+    if n.batch_requested {
+        return Ok(());
+    }
+    n.batch_requested = true;
+    ctx.db.notarization().notarization_id().update(n);
+    Ok(())
+}
+
+// Withdraws a batching request that has not been picked up by a batch yet
+#[reducer]
+pub fn cancel_batch_anchoring(ctx: &ReducerContext, notarization_id: u64) -> Result<(), String> {
+    let mut n = ctx.db.notarization().notarization_id().find(notarization_id).ok_or("Notarization not found")?;
+    require_owner(ctx, n.application_id)?;
+    if let Some(batch_id) = n.batch_id {
+        return Err(format!("Notarization is already part of Merkle batch {}", batch_id));
+    }
+    n.batch_requested = false;
+    ctx.db.notarization().notarization_id().update(n);
+    Ok(())
+}
+
+// Records the transaction that anchored a batch root and gives each leaf application its blockchain record
+#[reducer]
+pub fn anchor_merkle_batch(ctx: &ReducerContext, batch_id: u64, tx_hash: String, network: String) -> Result<(), String> {
+    require_admin(ctx)?;
+    let mut batch = ctx.db.merkle_batch().batch_id().find(batch_id).ok_or("Merkle batch not found")?;
+    if batch.tx_hash.is_some() {
+        return Err("Merkle batch is already anchored".into());
+    }
+    let tx_hash = tx_hash.trim().to_string();
+    if tx_hash.is_empty() {
+        return Err("Transaction hash is required".into());
+    }
+    for leaf in &batch.leaves {
+        let Some(mut n) = ctx.db.notarization().notarization_id().find(leaf.notarization_id) else {
+            continue;
+        };
+        let record = insert_blockchain_record(ctx, leaf.application_id, tx_hash.clone(), network.clone());
+        ctx.db.blockchain_record_batch().insert(BlockchainRecordBatch {
+            record_id: record.record_id,
+            batch_id,
+            notarization_id: leaf.notarization_id,
+            merkle_root: batch.root.clone(),
+        });
+        n.record_id = Some(record.record_id);
+        ctx.db.notarization().notarization_id().update(n);
+    }
+    batch.tx_hash = Some(tx_hash);
+    batch.network = network;
+    batch.anchored_at = Some(ctx.timestamp);
+    ctx.db.merkle_batch().batch_id().update(batch);
+    Ok(())
+}
+
+// Checks that the notarization's digest, through its stored proof, hashes to the recorded batch root
+#[reducer]
+pub fn verify_merkle_inclusion(ctx: &ReducerContext, notarization_id: u64) -> Result<(), String> {
+    let n = ctx.db.notarization().notarization_id().find(notarization_id).ok_or("Notarization not found")?;
+    let batch = n
+        .batch_id
+        .and_then(|id| ctx.db.merkle_batch().batch_id().find(id))
+        .ok_or("Notarization has not been batched")?;
+    let leaf = batch
+        .leaves
+        .iter()
+        .find(|l| l.notarization_id == notarization_id)
+        .ok_or("Notarization has not been batched")?;
+    let leaf_hash = merkle_leaf_hash(&n.digest).ok_or("Notarization digest is not valid hex")?;
+    if hex_encode(&leaf_hash) != leaf.leaf_hash {
+        return Err("Leaf hash does not match the notarization digest".into());
+    }
+    let root = merkle_root_from_proof(leaf_hash, &leaf.proof).ok_or("Proof contains an invalid node hash")?;
+    if hex_encode(&root) != batch.root {
+        return Err(format!("Proof does not lead to the root of batch {}", batch.batch_id));
+    }
+    spacetimedb::log::info!("Notarization {} verified against root {} of batch {}", notarization_id, batch.root, batch.batch_id);
+    Ok(())
+}
+
+// Checks an externally held digest and proof against a recorded batch root
+#[reducer]
+pub fn verify_merkle_proof(
+    ctx: &ReducerContext,
+    batch_id: u64,
+    digest: String,
+    proof: Vec<MerkleProofStep>,
+) -> Result<(), String> {
+    let batch = ctx.db.merkle_batch().batch_id().find(batch_id).ok_or("Merkle batch not found")?;
+    let leaf_hash = merkle_leaf_hash(digest.trim()).ok_or("Digest is not valid hex")?;
+    let root = merkle_root_from_proof(leaf_hash, &proof).ok_or("Proof contains an invalid node hash")?;
+    if hex_encode(&root) != batch.root {
+        return Err(format!("Proof does not lead to the root of batch {}", batch_id));
+    }
+    spacetimedb::log::info!("Digest {} verified against root {} of batch {}", digest, batch.root, batch_id);
+    Ok(())
+}
+
+// ---------- Document Diffs ----------
+
+// Above this many LCS cells a changed block is shown as a whole deletion plus insertion
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+// Longest-common-subsequence diff of two token slices, after trimming the common prefix and suffix
+fn diff_tokens<'a>(a: &[&'a str], b: &[&'a str]) -> Vec<(DiffOp, &'a str)> {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let a_mid = &a[prefix..a.len() - suffix];
+    let b_mid = &b[prefix..b.len() - suffix];
+
+    let mut out: Vec<(DiffOp, &'a str)> = a[..prefix].iter().map(|t| (DiffOp::Equal, *t)).collect();
+    let (n, m) = (a_mid.len(), b_mid.len());
+    if n * m > MAX_DIFF_CELLS {
+        out.extend(a_mid.iter().map(|t| (DiffOp::Delete, *t)));
+        out.extend(b_mid.iter().map(|t| (DiffOp::Insert, *t)));
+    } else {
+        // lcs[i * (m + 1) + j] is the LCS length of a_mid[i..] and b_mid[j..]
+        let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[i * (m + 1) + j] = if a_mid[i] == b_mid[j] {
+                    lcs[(i + 1) * (m + 1) + j + 1] + 1
+                } else {
+                    lcs[(i + 1) * (m + 1) + j].max(lcs[i * (m + 1) + j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < n && j < m {
+            if a_mid[i] == b_mid[j] {
+                out.push((DiffOp::Equal, a_mid[i]));
+                i += 1;
+                j += 1;
+            } else if lcs[(i + 1) * (m + 1) + j] >= lcs[i * (m + 1) + j + 1] {
+                out.push((DiffOp::Delete, a_mid[i]));
+                i += 1;
+            } else {
+                out.push((DiffOp::Insert, b_mid[j]));
+                j += 1;
+            }
+        }
+        out.extend(a_mid[i..].iter().map(|t| (DiffOp::Delete, *t)));
+        out.extend(b_mid[j..].iter().map(|t| (DiffOp::Insert, *t)));
+    }
+    out.extend(a[a.len() - suffix..].iter().map(|t| (DiffOp::Equal, *t)));
+    out
+}
+
+// Splits text into alternating runs of whitespace and non-whitespace so it can be rebuilt exactly
+fn word_tokens(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut in_space: Option<bool> = None;
+    for (i, c) in text.char_indices() {
+        let space = c.is_whitespace();
+        if in_space.is_some_and(|s| s != space) {
+            tokens.push(&text[start..i]);
+            start = i;
+        }
+        in_space = Some(space);
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+fn push_segment(segments: &mut Vec<DiffSegment>, op: DiffOp, text: &str) {
+    match segments.last_mut() {
+        Some(last) if last.op == op => last.text.push_str(text),
+        _ => segments.push(DiffSegment { op, text: text.to_string() }),
+    }
+}
+
+// Lines are matched first; each block of changed lines is then diffed word by word
+fn diff_documents(old: &str, new: &str) -> Vec<DiffSegment> {
+    let old_lines: Vec<&str> = old.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new.split_inclusive('\n').collect();
+    let mut segments: Vec<DiffSegment> = Vec::new();
+    let mut deleted = String::new();
+    let mut inserted = String::new();
+    for (op, line) in diff_tokens(&old_lines, &new_lines) {
+        match op {
+            DiffOp::Equal => {
+                push_changed_block(&mut segments, &mut deleted, &mut inserted);
+                push_segment(&mut segments, DiffOp::Equal, line);
+            }
+            DiffOp::Delete => deleted.push_str(line),
+            DiffOp::Insert => inserted.push_str(line),
+        }
+    }
+    push_changed_block(&mut segments, &mut deleted, &mut inserted);
+    segments
+}
+
+fn push_changed_block(segments: &mut Vec<DiffSegment>, deleted: &mut String, inserted: &mut String) {
+    if deleted.is_empty() && inserted.is_empty() {
+        return;
+    }
+    for (op, token) in diff_tokens(&word_tokens(deleted), &word_tokens(inserted)) {
+        push_segment(segments, op, token);
+    }
+    deleted.clear();
+    inserted.clear();
+}
+
+fn count_words(text: &str) -> u32 {
+    text.split_whitespace().count() as u32
+}
+
+fn escape_markup(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn redline_html(segments: &[DiffSegment]) -> String {
+    let mut html = String::new();
+    for seg in segments {
+        let text = escape_markup(&seg.text);
+        match seg.op {
+            DiffOp::Equal => html.push_str(&text),
+            DiffOp::Insert => html.push_str(&format!("<ins>{}</ins>", text)),
+            DiffOp::Delete => html.push_str(&format!("<del>{}</del>", text)),
+        }
+    }
+    html
+}
+
+#[reducer]
+pub fn compute_document_diff(ctx: &ReducerContext, from_version_id: u64, to_version_id: u64) -> Result<(), String> {
+    let from = ctx.db.document_version().version_id().find(from_version_id).ok_or("From version not found")?;
+    let to = ctx.db.document_version().version_id().find(to_version_id).ok_or("To version not found")?;
+    if from.application_id != to.application_id || from.doc_type != to.doc_type {
+        return Err("Versions must belong to the same document".into());
+    }
+    require_owner(ctx, from.application_id)?;
+    let exists = ctx
+        .db
+        .document_diff()
+        .application_id()
+        .filter(from.application_id)
+        .any(|d| d.from_version_id == from_version_id && d.to_version_id == to_version_id);
+    if exists {
+        return Ok(());
+    }
+
+    let segments = diff_documents(&from.content, &to.content);
+    let mut inserted_words = 0;
+    let mut deleted_words = 0;
+    for seg in &segments {
+        match seg.op {
+            DiffOp::Insert => inserted_words += count_words(&seg.text),
+            DiffOp::Delete => deleted_words += count_words(&seg.text),
+            DiffOp::Equal => {}
+        }
+    }
+    ctx.db.document_diff().insert(DocumentDiff {
+        diff_id: 0,
+        application_id: from.application_id,
+        doc_type: from.doc_type,
+        from_version_id,
+        to_version_id,
+        redline: redline_html(&segments),
+        segments,
+        inserted_words,
+        deleted_words,
+        created_by: ctx.sender,
+        created_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+// ---------- Inventorship ----------
+
+// Filing needs at least one named inventor, and every named inventor must have confirmed
+fn require_inventors_confirmed(ctx: &ReducerContext, application_id: u64) -> Result<(), String> {
+    let inventors: Vec<ApplicationInventor> = ctx.db.application_inventor().application_id().filter(application_id).collect();
+    if inventors.is_empty() {
+        return Err("Name at least one inventor before filing".into());
+    }
+    let unconfirmed = inventors.iter().filter(|i| i.declaration_status != DeclarationStatus::Confirmed).count();
+    if unconfirmed > 0 {
+        return Err(format!("{} named inventor(s) have not confirmed inventorship", unconfirmed));
+    }
+    Ok(())
+}
+
+fn find_application_inventor(ctx: &ReducerContext, application_id: u64, inventor: Identity) -> Option<ApplicationInventor> {
+    ctx.db
+        .application_inventor()
+        .application_id()
+        .filter(application_id)
+        .find(|i| i.inventor == inventor)
+}
+
+// Names an inventor, or updates how they are named. Any change resets their declaration to Pending.
+#[reducer]
+pub fn set_application_inventor(
+    ctx: &ReducerContext,
+    application_id: u64,
+    inventor: Identity,
+    position: u32,
+    contribution: String,
+    contribution_percent: Option<u8>,
+) -> Result<(), String> {
+    require_owner(ctx, application_id)?;
+    if ctx.db.inventor().identity().find(inventor).is_none() {
+        return Err("Inventor has no registered profile".into());
+    }
+    if position == 0 {
+        return Err("Position starts at 1".into());
+    }
+    let existing = find_application_inventor(ctx, application_id, inventor);
+    let others: Vec<ApplicationInventor> = ctx
+        .db
+        .application_inventor()
+        .application_id()
+        .filter(application_id)
+        .filter(|i| i.inventor != inventor)
+        .collect();
+    if others.iter().any(|i| i.position == position) {
+        return Err(format!("Position {} is already taken", position));
+    }
+    let total_percent: u32 = others.iter().filter_map(|i| i.contribution_percent).map(u32::from).sum::<u32>()
+        + contribution_percent.map(u32::from).unwrap_or(0);
+    if total_percent > 100 {
+        return Err("Contribution percentages cannot exceed 100 in total".into());
+    }
+
+    let row = ApplicationInventor {
+        row_id: existing.as_ref().map(|i| i.row_id).unwrap_or(0),
+        application_id,
+        inventor,
+        position,
+        contribution,
+        contribution_percent,
+        declaration_status: DeclarationStatus::Pending,
+        dispute_reason: String::new(),
+        updated_at: ctx.timestamp,
+    };
+    if existing.is_some() {
+        ctx.db.application_inventor().row_id().update(row);
+    } else {
+        ctx.db.application_inventor().insert(row);
+        record_event(ctx, application_id, ApplicationEventPayload::InventorNamed(inventor));
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn remove_application_inventor(ctx: &ReducerContext, application_id: u64, inventor: Identity) -> Result<(), String> {
+    require_owner(ctx, application_id)?;
+    let row = find_application_inventor(ctx, application_id, inventor).ok_or("Inventor is not named on this application")?;
+    ctx.db.application_inventor().row_id().delete(row.row_id);
+    Ok(())
+}
+
+#[reducer]
+pub fn confirm_inventorship(ctx: &ReducerContext, application_id: u64) -> Result<(), String> {
+    let mut row = find_application_inventor(ctx, application_id, ctx.sender).ok_or("You are not named on this application")?;
+    row.declaration_status = DeclarationStatus::Confirmed;
+    row.dispute_reason = String::new();
+    row.updated_at = ctx.timestamp;
+    ctx.db.application_inventor().row_id().update(row);
+    record_event(ctx, application_id, ApplicationEventPayload::InventorConfirmed(ctx.sender));
+    Ok(())
+}
+
+#[reducer]
+pub fn dispute_inventorship(ctx: &ReducerContext, application_id: u64, reason: String) -> Result<(), String> {
+    let mut row = find_application_inventor(ctx, application_id, ctx.sender).ok_or("You are not named on this application")?;
+    if reason.trim().is_empty() {
+        return Err("A reason is required to dispute inventorship".into());
+    }
+    row.declaration_status = DeclarationStatus::Disputed;
+    row.dispute_reason = reason;
+    row.updated_at = ctx.timestamp;
+    ctx.db.application_inventor().row_id().update(row);
+    record_event(ctx, application_id, ApplicationEventPayload::InventorshipDisputed(ctx.sender));
+    Ok(())
+}
+
+// ---------- Classification ----------
+
+// CPC subclasses with their technology area and the keywords that suggest them
+const CPC_KEYWORDS: &[(&str, &str, &[&str])] = &[
+    ("G06N", "AI", &["neural", "machine learning", "artificial intelligence", "training data", "inference", "model weights", "deep learning"]),
+    ("G06F", "Computing", &["processor", "memory", "software", "database", "user interface", "operating system", "computing device"]),
+    ("G06Q", "Fintech", &["payment", "transaction", "commerce", "marketplace", "billing", "financial", "subscription"]),
+    ("G06V", "AI", &["image recognition", "object detection", "facial recognition", "computer vision", "video analysis"]),
+    ("G06T", "Computing", &["image processing", "rendering", "3d model", "texture", "pixel"]),
+    ("G16H", "Healthcare", &["patient", "medical record", "clinical", "diagnosis data", "healthcare"]),
+    ("H04L", "Networking", &["network", "packet", "protocol", "encryption", "blockchain", "cryptographic", "server"]),
+    ("H04W", "Networking", &["wireless", "cellular", "base station", "5g", "bluetooth", "antenna"]),
+    ("H01L", "Semiconductors", &["semiconductor", "transistor", "wafer", "substrate", "integrated circuit"]),
+    ("H01M", "Energy", &["battery", "electrode", "electrolyte", "cathode", "anode", "fuel cell"]),
+    ("H02J", "Energy", &["power grid", "charging", "power supply", "energy storage", "inverter"]),
+    ("B60L", "Mobility", &["electric vehicle", "traction motor", "regenerative braking", "vehicle charging"]),
+    ("B33Y", "Manufacturing", &["additive manufacturing", "3d printing", "layer by layer", "printed object"]),
+    ("A61B", "Healthcare", &["surgical", "sensor for measuring", "imaging device", "endoscope", "heart rate"]),
+    ("A61K", "Biotech", &["pharmaceutical", "compound", "dosage", "drug", "formulation"]),
+    ("C12N", "Biotech", &["gene", "dna", "rna", "crispr", "cell line", "enzyme"]),
+    ("F03D", "Energy", &["wind turbine", "rotor blade", "nacelle"]),
+];
+
+const MAX_CLASSIFICATION_SUGGESTIONS: usize = 5;
+
+// "G06F 16/00" -> "G06F"
+fn classification_subclass(code: &str) -> String {
+    code.trim().chars().take(4).collect::<String>().to_uppercase()
+}
+
+fn classification_scheme_prefix(scheme: &ClassificationScheme) -> &'static str {
+    match scheme {
+        ClassificationScheme::Ipc => "IPC",
+        ClassificationScheme::Cpc => "CPC",
+    }
+}
+
+fn technology_area(subclass: &str) -> Option<&'static str> {
+    CPC_KEYWORDS.iter().find(|(code, ..)| *code == subclass).map(|(_, area, _)| *area)
+}
+
+// Section letter, two-digit class and subclass letter, optionally followed by a group
+fn is_valid_classification_code(code: &str) -> bool {
+    let chars: Vec<char> = code.chars().collect();
+    chars.len() >= 4
+        && "ABCDEFGHY".contains(chars[0])
+        && chars[1].is_ascii_digit()
+        && chars[2].is_ascii_digit()
+        && chars[3].is_ascii_uppercase()
+}
+
+fn clear_primary_classification(ctx: &ReducerContext, application_id: u64) {
+    let primaries: Vec<Classification> = ctx
+        .db
+        .classification()
+        .application_id()
+        .filter(application_id)
+        .filter(|c| c.primary)
+        .collect();
+    for mut c in primaries {
+        c.primary = false;
+        ctx.db.classification().classification_id().update(c);
+    }
+}
+
+// Replaces the pending suggestions with CPC subclasses whose keywords appear in the abstract and claims
+#[reducer]
+pub fn suggest_classifications(ctx: &ReducerContext, application_id: u64) -> Result<(), String> {
+    let app = require_owner(ctx, application_id)?;
+    // Pad every word with spaces so keywords only match whole words ("gene" not in "general")
+    let words: Vec<String> = format!("{} {} {}", app.title, app.abstract_text, app.claims_text)
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect();
+    let text = format!(" {} ", words.join(" "));
+
+    let existing: Vec<Classification> = ctx.db.classification().application_id().filter(application_id).collect();
+    for c in &existing {
+        if c.source == ClassificationSource::Suggested {
+            ctx.db.classification().classification_id().delete(c.classification_id);
+        }
+    }
+
+    let mut scored: Vec<(&str, f32)> = CPC_KEYWORDS
+        .iter()
+        .filter(|(code, ..)| !existing.iter().any(|c| c.source != ClassificationSource::Suggested && classification_subclass(&c.code) == *code))
+        .map(|(code, _, keywords)| {
+            let hits: usize = keywords.iter().map(|k| text.matches(&format!(" {} ", k)).count()).sum();
+            (*code, hits as f32 / keywords.len() as f32)
+        })
+        .filter(|(_, score)| *score > 0.0)
+        .collect();
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    for (code, score) in scored.into_iter().take(MAX_CLASSIFICATION_SUGGESTIONS) {
+        ctx.db.classification().insert(Classification {
+            classification_id: 0,
+            application_id,
+            scheme: ClassificationScheme::Cpc,
+            code: code.to_string(),
+            primary: false,
+            source: ClassificationSource::Suggested,
+            score,
+            created_at: ctx.timestamp,
+        });
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn confirm_classification(ctx: &ReducerContext, classification_id: u64, primary: bool) -> Result<(), String> {
+    let mut c = ctx.db.classification().classification_id().find(classification_id).ok_or("Classification not found")?;
+    require_owner(ctx, c.application_id)?;
+    if primary {
+        clear_primary_classification(ctx, c.application_id);
+    }
+    c.source = ClassificationSource::Confirmed;
+    c.primary = primary;
+    let application_id = c.application_id;
+    ctx.db.classification().classification_id().update(c);
+    refresh_filing_readiness(ctx, application_id);
+    Ok(())
+}
+
+// Adds a classification by hand, overriding any suggestion for the same code
+#[reducer]
+pub fn add_classification(
+    ctx: &ReducerContext,
+    application_id: u64,
+    scheme: ClassificationScheme,
+    code: String,
+    primary: bool,
+) -> Result<(), String> {
+    require_owner(ctx, application_id)?;
+    let code = code.trim().to_uppercase();
+    if !is_valid_classification_code(&code) {
+        return Err(format!("'{}' is not a valid IPC/CPC code", code));
+    }
+    let same: Vec<Classification> = ctx
+        .db
+        .classification()
+        .application_id()
+        .filter(application_id)
+        .filter(|c| c.scheme == scheme && c.code == code)
+        .collect();
+    for c in same {
+        ctx.db.classification().classification_id().delete(c.classification_id);
+    }
+    if primary {
+        clear_primary_classification(ctx, application_id);
+    }
+    ctx.db.classification().insert(Classification {
+        classification_id: 0,
+        application_id,
+        scheme,
+        code,
+        primary,
+        source: ClassificationSource::Manual,
+        score: 0.0,
+        created_at: ctx.timestamp,
+    });
+    refresh_filing_readiness(ctx, application_id);
+    Ok(())
+}
+
+#[reducer]
+pub fn remove_classification(ctx: &ReducerContext, classification_id: u64) -> Result<(), String> {
+    let c = ctx.db.classification().classification_id().find(classification_id).ok_or("Classification not found")?;
+    require_owner(ctx, c.application_id)?;
+    ctx.db.classification().classification_id().delete(classification_id);
+    refresh_filing_readiness(ctx, c.application_id);
+    Ok(())
+}
+
+// ---------- Jurisdictions ----------
+
+// Analytics segment name for an office, as used in market_trend_snapshot
+fn office_segment(office: &PatentOffice) -> &'static str {
+    match office {
+        PatentOffice::Uspto => "US",
+        PatentOffice::Epo => "EP",
+        PatentOffice::Jpo => "JP",
+        PatentOffice::Cnipa => "CN",
+        PatentOffice::Wipo => "WO",
+    }
+}
+
+#[reducer]
+pub fn add_jurisdiction_filing(
+    ctx: &ReducerContext,
+    application_id: u64,
+    office: PatentOffice,
+    application_number: String,
+    filing_date: Timestamp,
+) -> Result<(), String> {
+    require_owner(ctx, application_id)?;
+    require_inventors_confirmed(ctx, application_id)?;
+    let exists = ctx
+        .db
+        .jurisdiction_filing()
+        .application_id()
+        .filter(application_id)
+        .any(|f| f.office == office);
+    if exists {
+        return Err(format!("Application is already filed with {:?}", office));
+    }
+    ctx.db.jurisdiction_filing().insert(JurisdictionFiling {
+        filing_id: 0,
+        application_id,
+        office,
+        application_number: application_number.trim().to_string(),
+        filing_date,
+        status: PatentStatus::Submitted,
+        created_at: ctx.timestamp,
+        updated_at: ctx.timestamp,
+    });
+    refresh_filing_readiness(ctx, application_id);
+    Ok(())
+}
+
+#[reducer]
+pub fn update_jurisdiction_status(ctx: &ReducerContext, filing_id: u64, status: PatentStatus) -> Result<(), String> {
+    let mut filing = ctx.db.jurisdiction_filing().filing_id().find(filing_id).ok_or("Jurisdiction filing not found")?;
+    require_owner(ctx, filing.application_id)?;
+    filing.status = status;
+    filing.updated_at = ctx.timestamp;
+    ctx.db.jurisdiction_filing().filing_id().update(filing);
+    Ok(())
+}
+
+// ---------- Patent Families ----------
+
+// Earliest date among the application's own filing/priority dates and everything it claims priority from
+fn earliest_priority_date(ctx: &ReducerContext, application_id: u64) -> Option<Timestamp> {
+    let mut earliest: Option<Timestamp> = None;
+    let mut consider = |at: Timestamp| {
+        if earliest.is_none_or(|e| at < e) {
+            earliest = Some(at);
+        }
+    };
+    let mut visited: Vec<u64> = Vec::new();
+    let mut queue = vec![application_id];
+    while let Some(id) = queue.pop() {
+        if visited.contains(&id) {
+            continue;
+        }
+        visited.push(id);
+        if let Some(f) = ctx.db.filing_detail().application_id().find(id) {
+            consider(f.priority_date.unwrap_or(f.filing_date));
+        }
+        for link in ctx.db.family_link().child_application_id().filter(id) {
+            consider(link.priority_date);
+            queue.push(link.parent_application_id);
+        }
+    }
+    earliest
+}
+
+// True if `target` can be reached from `from` by following parent -> child links
+fn is_descendant(ctx: &ReducerContext, from: u64, target: u64) -> bool {
+    let mut visited: Vec<u64> = Vec::new();
+    let mut queue = vec![from];
+    while let Some(id) = queue.pop() {
+        if id == target {
+            return true;
+        }
+        if visited.contains(&id) {
+            continue;
+        }
+        visited.push(id);
+        queue.extend(ctx.db.family_link().parent_application_id().filter(id).map(|l| l.child_application_id));
+    }
+    false
+}
+
+// Every application connected to `application_id` through family links, in either direction
+fn family_members(ctx: &ReducerContext, application_id: u64) -> Vec<u64> {
+    let mut members: Vec<u64> = Vec::new();
+    let mut queue = vec![application_id];
+    while let Some(id) = queue.pop() {
+        if members.contains(&id) {
+            continue;
+        }
+        members.push(id);
+        queue.extend(ctx.db.family_link().parent_application_id().filter(id).map(|l| l.child_application_id));
+        queue.extend(ctx.db.family_link().child_application_id().filter(id).map(|l| l.parent_application_id));
+    }
+    members
+}
+
+fn primary_parent_link(ctx: &ReducerContext, application_id: u64) -> Option<FamilyLink> {
+    ctx.db
+        .family_link()
+        .child_application_id()
+        .filter(application_id)
+        .min_by_key(|l| (l.priority_date.to_micros_since_unix_epoch(), l.link_id))
+}
+
+// Rebuilds the family_node rows of every family containing one of `application_ids`
+fn rebuild_family_tree(ctx: &ReducerContext, application_ids: &[u64]) {
+    let mut done: Vec<u64> = Vec::new();
+    for id in application_ids {
+        if done.contains(id) {
+            continue;
+        }
+        let members = family_members(ctx, *id);
+        for member in &members {
+            // Links are acyclic, so walking primary parents always terminates at a root
+            let parent_link = primary_parent_link(ctx, *member);
+            let mut root = *member;
+            let mut depth: u32 = 0;
+            while let Some(link) = primary_parent_link(ctx, root) {
+                root = link.parent_application_id;
+                depth += 1;
+            }
+            let row = FamilyNode {
+                application_id: *member,
+                root_application_id: root,
+                parent_application_id: parent_link.as_ref().map(|l| l.parent_application_id),
+                relationship: parent_link.map(|l| l.relationship),
+                depth,
+                earliest_priority_date: earliest_priority_date(ctx, *member),
+                updated_at: ctx.timestamp,
+            };
+            if ctx.db.family_node().application_id().find(*member).is_some() {
+                ctx.db.family_node().application_id().update(row);
+            } else {
+                ctx.db.family_node().insert(row);
+            }
+        }
+        done.extend(members);
+    }
+}
+
+#[reducer]
+pub fn link_family(
+    ctx: &ReducerContext,
+    parent_application_id: u64,
+    child_application_id: u64,
+    relationship: FamilyRelationship,
+    priority_date: Timestamp,
+) -> Result<(), String> {
+    require_owner(ctx, parent_application_id)?;
+    require_owner(ctx, child_application_id)?;
+    if parent_application_id == child_application_id {
+        return Err("An application cannot claim priority from itself".into());
+    }
+    let duplicate = ctx
+        .db
+        .family_link()
+        .child_application_id()
+        .filter(child_application_id)
+        .any(|l| l.parent_application_id == parent_application_id);
+    if duplicate {
+        return Err("These applications are already linked".into());
+    }
+    if is_descendant(ctx, child_application_id, parent_application_id) {
+        return Err("Link would create a cycle in the patent family".into());
+    }
+    let inserted = ctx.db.family_link().insert(FamilyLink {
+        link_id: 0,
+        parent_application_id,
+        child_application_id,
+        relationship,
+        priority_date,
+        created_by: ctx.sender,
+        created_at: ctx.timestamp,
+    });
+    record_event(ctx, child_application_id, ApplicationEventPayload::FamilyLinked(inserted.link_id));
+    rebuild_family_tree(ctx, &[child_application_id]);
+    for id in family_members(ctx, child_application_id) {
+        recompute_deadlines(ctx, id);
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn unlink_family(ctx: &ReducerContext, link_id: u64) -> Result<(), String> {
+    let link = ctx.db.family_link().link_id().find(link_id).ok_or("Family link not found")?;
+    require_owner(ctx, link.child_application_id)?;
+    ctx.db.family_link().link_id().delete(link_id);
+
+    // A member that ends up alone no longer belongs to a family
+    let ends = [link.parent_application_id, link.child_application_id];
+    let mut still_linked: Vec<u64> = Vec::new();
+    for id in ends {
+        if family_members(ctx, id).len() > 1 {
+            still_linked.push(id);
+        } else {
+            ctx.db.family_node().application_id().delete(id);
+        }
+    }
+    rebuild_family_tree(ctx, &still_linked);
+    for id in ends {
+        for member in family_members(ctx, id) {
+            recompute_deadlines(ctx, member);
+        }
+    }
+    Ok(())
+}
+
+// ---------- Scheduled Reducers ----------
+
+// Alert type of the automated check rows monitor_tick records; they are bookkeeping, not findings
+const MONITOR_HEARTBEAT_ALERT: &str = "monitor_heartbeat";
+
+#[reducer]
+pub fn monitor_tick(ctx: &ReducerContext, _info: MonitoringSchedule) -> Result<(), String> {
+    // Security: only scheduler triggers this reducer
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer 'monitor_tick' may only be invoked by scheduling.".into());
+    }
+
+    // Simple automated monitoring demo: ensure each Submitted/Examination app has a heartbeat alert once
+    for app in ctx.db.patent_application().iter() {
+        if app.status == PatentStatus::Submitted || app.status == PatentStatus::Examination {
+            let mut has_heartbeat = false;
+            for a in ctx.db.infringement_alert().iter() {
+                if a.application_id == app.application_id && a.alert_type == MONITOR_HEARTBEAT_ALERT {
+                    has_heartbeat = true;
+                    break;
+                }
+            }
+            if !has_heartbeat {
+                let alert = InfringementAlert {
+                    alert_id: 0,
+                    application_id: app.application_id,
+                    alert_type: MONITOR_HEARTBEAT_ALERT.to_string(),
+                    severity: AlertSeverity::Low,
+                    description: "Automated monitoring check executed".to_string(),
+                    detected_at: ctx.timestamp,
+                    resolved: true,
+                };
+                let inserted = ctx.db.infringement_alert().insert(alert);
+                let event = AlertEvent {
+                    alert_id: inserted.alert_id,
+                    alert_type: inserted.alert_type,
+                    severity: inserted.severity,
+                };
+                record_event(ctx, app.application_id, ApplicationEventPayload::AlertRaised(event));
+            }
+        }
+    }
+
+    spacetimedb::log::debug!("monitor_tick completed");
+    Ok(())
+}
+
+// Rolling windows reported alongside the all-time/current aggregates, as (label, seconds)
+const TREND_WINDOWS: [(&str, u64); 3] = [("24h", 86_400), ("7d", 7 * 86_400), ("30d", 30 * 86_400)];
+
+fn within_window(now: Timestamp, at: Timestamp, window_secs: u64) -> bool {
+    let age_micros = now.to_micros_since_unix_epoch() - at.to_micros_since_unix_epoch();
+    age_micros >= 0 && (age_micros as u64) <= window_secs * 1_000_000
+}
+
+// Filing/grant/alert counts for one segment or portfolio, windows indexed like TREND_WINDOWS
+#[derive(Default)]
+struct TrendCounts {
+    filings: u64,
+    grants: u64,
+    open_alerts: u64,
+    window_filings: [u64; TREND_WINDOWS.len()],
+    window_grants: [u64; TREND_WINDOWS.len()],
+    window_alerts: [u64; TREND_WINDOWS.len()],
+}
+
+impl TrendCounts {
+    fn add_application(&mut self, now: Timestamp, app: &PatentApplication, granted_at: Option<Timestamp>) {
+        self.add_filing(now, app.created_at, &app.status, granted_at);
+    }
+
+    // Grants only count towards a window when the grant date is known
+    fn add_filing(&mut self, now: Timestamp, filed_at: Timestamp, status: &PatentStatus, granted_at: Option<Timestamp>) {
+        self.filings += 1;
+        let granted = *status == PatentStatus::Granted;
+        if granted {
+            self.grants += 1;
+        }
+        for (i, (_, secs)) in TREND_WINDOWS.iter().enumerate() {
+            if within_window(now, filed_at, *secs) {
+                self.window_filings[i] += 1;
+            }
+            if granted && granted_at.is_some_and(|at| within_window(now, at, *secs)) {
+                self.window_grants[i] += 1;
+            }
+        }
+    }
+
+    // Windows count the alerts raised in them, resolved or not, while the "current" count only
+    // has the open ones; monitoring heartbeats are not findings and count towards neither
+    fn add_alert(&mut self, now: Timestamp, alert: &InfringementAlert) {
+        if alert.alert_type == MONITOR_HEARTBEAT_ALERT {
+            return;
+        }
+        if !alert.resolved {
+            self.open_alerts += 1;
+        }
+        for (i, (_, secs)) in TREND_WINDOWS.iter().enumerate() {
+            if within_window(now, alert.detected_at, *secs) {
+                self.window_alerts[i] += 1;
+            }
+        }
+    }
+
+    // (metric, value, window) rows to record for these counts
+    fn rows(&self) -> Vec<(TrendMetric, u64, &'static str)> {
+        let mut rows = vec![
+            (TrendMetric::FilingCount, self.filings, "all_time"),
+            (TrendMetric::GrantCount, self.grants, "all_time"),
+            (TrendMetric::AlertCount, self.open_alerts, "current"),
+        ];
+        for (i, (window, _)) in TREND_WINDOWS.iter().enumerate() {
+            rows.push((TrendMetric::FilingCount, self.window_filings[i], window));
+            rows.push((TrendMetric::GrantCount, self.window_grants[i], window));
+            rows.push((TrendMetric::AlertCount, self.window_alerts[i], window));
+        }
+        rows
     }
 }
 
-#[reducer]
-pub fn submit_patent(
-    ctx: &ReducerContext,
-    title: String,
-    abstract_text: String,
-    claims_text: String,
-) -> Result<(), String> {
-    let now = ctx.timestamp;
-    let row = PatentApplication {
-        application_id: 0,
-        owner: ctx.sender,
-        title,
-        abstract_text,
-        claims_text,
-        status: PatentStatus::Submitted,
-        created_at: now,
-        last_updated: now,
-    };
-    match ctx.db.patent_application().try_insert(row) {
-        Ok(inserted) => {
-            spacetimedb::log::info!("Patent submitted by {} app_id={}", inserted.owner, inserted.application_id);
-            Ok(())
+// The recorded grant date, else when the application first entered the Grant stage.
+// last_updated is not used because later activity (e.g., maintenance payments) touches it.
+fn application_granted_at(ctx: &ReducerContext, app: &PatentApplication) -> Option<Timestamp> {
+    if app.status != PatentStatus::Granted {
+        return None;
+    }
+    ctx.db
+        .grant_record()
+        .application_id()
+        .find(app.application_id)
+        .map(|g| g.grant_date)
+        .or_else(|| {
+            ctx.db
+                .stage_transition()
+                .application_id()
+                .filter(app.application_id)
+                .find(|t| t.stage == Stage::Grant)
+                .map(|t| t.entered_at)
+        })
+}
+
+// Where an application's counts are rolled up to
+struct TrendKeys {
+    segments: Vec<String>,
+    holders: Vec<Identity>,
+    orgs: Vec<String>,
+}
+
+// Public market segments an application counts towards; suggested classifications don't count
+// until they are confirmed
+fn application_segments(ctx: &ReducerContext, app: &PatentApplication) -> Vec<String> {
+    let mut segments = vec!["global".to_string()];
+    for c in ctx.db.classification().application_id().filter(app.application_id) {
+        if c.source == ClassificationSource::Suggested {
+            continue;
+        }
+        let subclass = classification_subclass(&c.code);
+        let mut keys = vec![format!("{}:{}", classification_scheme_prefix(&c.scheme), subclass)];
+        if let Some(area) = technology_area(&subclass) {
+            keys.push(area.to_string());
+        }
+        for key in keys {
+            if !segments.contains(&key) {
+                segments.push(key);
+            }
         }
-        Err(e) => Err(format!("Failed to submit patent: {}", e)),
     }
+    segments
 }
 
-#[reducer]
-pub fn update_patent_status(
-    ctx: &ReducerContext,
-    application_id: u64,
-    new_status: PatentStatus,
-) -> Result<(), String> {
-    if let Some(mut app) = ctx.db.patent_application().application_id().find(application_id) {
-        if app.owner != ctx.sender {
-            return Err("Only the owner can update patent status".into());
+// The owner plus co-owners the owner granted; self-assigned portfolio roles are not holders
+fn application_holders(ctx: &ReducerContext, app: &PatentApplication) -> Vec<Identity> {
+    let mut holders = vec![app.owner];
+    for c in ctx.db.co_owner_grant().application_id().filter(app.application_id) {
+        if !holders.contains(&c.holder) {
+            holders.push(c.holder);
         }
-        app.status = new_status.clone();
-        app.last_updated = ctx.timestamp;
-        let status_to_log = format!("{:?}", new_status);
-        ctx.db.patent_application().application_id().update(app);
-        spacetimedb::log::info!("Updated application {} status to {}", application_id, status_to_log);
-        Ok(())
+    }
+    holders
+}
+
+fn organizations_of(ctx: &ReducerContext, identity: Identity) -> Vec<String> {
+    ctx.db.organization_member().member().filter(identity).map(|m| m.organization).collect()
+}
+
+fn insert_snapshot(ctx: &ReducerContext, segment: &str, metric: TrendMetric, value: u64, window: &str) {
+    ctx.db.market_trend_snapshot().insert(MarketTrendSnapshot {
+        snapshot_id: 0,
+        segment: segment.to_string(),
+        metric,
+        value: value as f64,
+        window: window.to_string(),
+        computed_at: ctx.timestamp,
+    });
+}
+
+// Private snapshots only keep the latest computation per viewer
+fn replace_portfolio_snapshots(ctx: &ReducerContext, viewer: Identity, scope: &str, counts: &TrendCounts) {
+    let stale: Vec<u64> = ctx
+        .db
+        .portfolio_trend_snapshot()
+        .viewer()
+        .filter(viewer)
+        .filter(|s| s.scope == scope)
+        .map(|s| s.snapshot_id)
+        .collect();
+    for id in stale {
+        ctx.db.portfolio_trend_snapshot().snapshot_id().delete(id);
+    }
+    for (metric, value, window) in counts.rows() {
+        ctx.db.portfolio_trend_snapshot().insert(PortfolioTrendSnapshot {
+            snapshot_id: 0,
+            viewer,
+            scope: scope.to_string(),
+            metric,
+            value: value as f64,
+            window: window.to_string(),
+            computed_at: ctx.timestamp,
+        });
+    }
+}
+
+// Applications with no stage progress for this long count as stalled
+const STALLED_AFTER_DAYS: u64 = 30;
+
+const PIPELINE_STAGES: [Stage; 9] = [
+    Stage::Idea,
+    Stage::Drafting,
+    Stage::PriorArt,
+    Stage::Filing,
+    Stage::Examination,
+    Stage::OfficeAction,
+    Stage::Appeal,
+    Stage::Grant,
+    Stage::Maintenance,
+];
+
+// Stages an application may skip on its way to grant; they are counted only when actually entered
+const OPTIONAL_PIPELINE_STAGES: [Stage; 2] = [Stage::OfficeAction, Stage::Appeal];
+
+fn is_optional_stage(stage: &Stage) -> bool {
+    OPTIONAL_PIPELINE_STAGES.contains(stage)
+}
+
+fn stage_rank(stage: &Stage) -> usize {
+    PIPELINE_STAGES.iter().position(|s| s == stage).unwrap_or(0)
+}
+
+fn days_between(from: Timestamp, to: Timestamp) -> f64 {
+    (to.to_micros_since_unix_epoch() - from.to_micros_since_unix_epoch()) as f64 / 86_400_000_000.0
+}
+
+fn median(values: &mut [f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        Some((values[mid - 1] + values[mid]) / 2.0)
     } else {
-        Err("Application not found".into())
+        Some(values[mid])
     }
 }
 
-#[reducer]
-pub fn add_prior_art_result(
-    ctx: &ReducerContext,
-    application_id: u64,
-    source: String,
-    url: String,
-    summary: String,
-    relevance_score: f32,
-) -> Result<(), String> {
-    let row = PriorArtResult {
-        result_id: 0,
-        application_id,
-        source,
-        url,
-        summary,
-        relevance_score,
-        found_at: ctx.timestamp,
-    };
-    match ctx.db.prior_art_result().try_insert(row) {
-        Ok(inserted) => {
-            spacetimedb::log::info!("Prior art result recorded for app_id={} result_id={}", inserted.application_id, inserted.result_id);
-            Ok(())
+// When an application entered each stage, oldest first. Stages recorded before
+// stage_transition existed fall back to their progress row's updated_at.
+fn stage_entries(ctx: &ReducerContext, application_id: u64) -> Vec<(Stage, Timestamp)> {
+    let mut entries: Vec<(Stage, Timestamp)> = Vec::new();
+    for t in ctx.db.stage_transition().application_id().filter(application_id) {
+        if !entries.iter().any(|(s, _)| *s == t.stage) {
+            entries.push((t.stage.clone(), t.entered_at));
+        }
+    }
+    for sp in ctx.db.stage_progress().application_id().filter(application_id) {
+        if !entries.iter().any(|(s, _)| *s == sp.stage) {
+            entries.push((sp.stage.clone(), sp.updated_at));
         }
-        Err(e) => Err(format!("Failed to add prior art: {}", e)),
     }
+    entries.sort_by_key(|(_, at)| at.to_micros_since_unix_epoch());
+    entries
 }
 
-#[reducer]
-pub fn upsert_document_generation(
-    ctx: &ReducerContext,
-    application_id: u64,
-    doc_type: DocType,
-    status: DocGenStatus,
-    error_message: String,
-) -> Result<(), String> {
-    // Try to find existing record for (application_id, doc_type)
-    let mut existing_id: Option<u64> = None;
-    for doc in ctx.db.document_generation().iter() {
-        if doc.application_id == application_id && doc.doc_type == doc_type {
-            existing_id = Some(doc.doc_id);
-            break;
-        }
-    }
-
-    if let Some(doc_id) = existing_id {
-        if let Some(mut doc) = ctx.db.document_generation().doc_id().find(doc_id) {
-            doc.status = status.clone();
-            doc.error_message = error_message.clone();
-            doc.updated_at = ctx.timestamp;
-            let log_status = format!("{:?}", status);
-            let log_type = format!("{:?}", doc_type);
-            ctx.db.document_generation().doc_id().update(doc);
-            spacetimedb::log::info!(
-                "Updated doc generation app_id={} type={} status={}",
-                application_id,
-                log_type,
-                log_status
-            );
+fn compute_pipeline_metrics(ctx: &ReducerContext) {
+    let now = ctx.timestamp;
+    let mut days_in_stage: Vec<Vec<f64>> = vec![Vec::new(); PIPELINE_STAGES.len()];
+    let mut reached = [0u64; PIPELINE_STAGES.len()];
+    let mut idea_to_filing: Vec<f64> = Vec::new();
+    let mut filing_to_grant: Vec<f64> = Vec::new();
+    let mut stalled: u64 = 0;
+    let mut open_apps: u64 = 0;
+
+    for app in ctx.db.patent_application().iter() {
+        let entries = stage_entries(ctx, app.application_id);
+        let closed = matches!(app.status, PatentStatus::Granted | PatentStatus::Rejected | PatentStatus::Abandoned);
+
+        for (i, (stage, entered_at)) in entries.iter().enumerate() {
+            let left_at = match entries.get(i + 1) {
+                Some((_, next)) => *next,
+                None if closed => continue,
+                None => now,
+            };
+            days_in_stage[stage_rank(stage)].push(days_between(*entered_at, left_at));
+        }
+
+        // An application that reached a stage has passed through every earlier mandatory one;
+        // optional stages only count when the application actually entered them
+        let furthest = entries.iter().map(|(s, _)| stage_rank(s)).max();
+        for (i, stage) in PIPELINE_STAGES.iter().enumerate() {
+            let hit = if is_optional_stage(stage) {
+                entries.iter().any(|(s, _)| s == stage)
+            } else {
+                furthest.is_some_and(|f| i <= f)
+            };
+            if hit {
+                reached[i] += 1;
+            }
+        }
+
+        let entered = |stage: Stage| entries.iter().find(|(s, _)| *s == stage).map(|(_, at)| *at);
+        let idea_at = entered(Stage::Idea).unwrap_or(app.created_at);
+        if let Some(filing_at) = entered(Stage::Filing) {
+            idea_to_filing.push(days_between(idea_at, filing_at));
+            if let Some(grant_at) = entered(Stage::Grant) {
+                filing_to_grant.push(days_between(filing_at, grant_at));
+            }
+        }
+
+        let last_progress = ctx
+            .db
+            .stage_progress()
+            .application_id()
+            .filter(app.application_id)
+            .map(|sp| sp.updated_at)
+            .max_by_key(|at| at.to_micros_since_unix_epoch())
+            .unwrap_or(app.created_at);
+        if !closed {
+            open_apps += 1;
+            if older_than(now, last_progress, STALLED_AFTER_DAYS * 86_400) {
+                stalled += 1;
+            }
         }
-    } else {
-        let doc = DocumentGeneration {
-            doc_id: 0,
-            application_id,
-            doc_type: doc_type.clone(),
-            status: status.clone(),
-            error_message: error_message.clone(),
-            updated_at: ctx.timestamp,
-        };
-        ctx.db.document_generation().insert(doc);
-        let log_status = format!("{:?}", status);
-        let log_type = format!("{:?}", doc_type);
-        spacetimedb::log::info!(
-            "Inserted doc generation app_id={} type={} status={}",
-            application_id,
-            log_type,
-            log_status
-        );
     }
-    Ok(())
-}
 
-#[reducer]
-pub fn add_blockchain_record(
-    ctx: &ReducerContext,
-    application_id: u64,
-    tx_hash: String,
-    network: String,
-) -> Result<(), String> {
-    let rec = BlockchainRecord {
-        record_id: 0,
-        application_id,
-        tx_hash,
-        network,
-        recorded_at: ctx.timestamp,
+    // Only the latest computation is kept
+    let stale: Vec<u64> = ctx.db.pipeline_metric().iter().map(|m| m.metric_id).collect();
+    for id in stale {
+        ctx.db.pipeline_metric().metric_id().delete(id);
+    }
+    let insert = |kind: PipelineMetricKind, stage: Option<Stage>, value: f64, sample_count: u64| {
+        ctx.db.pipeline_metric().insert(PipelineMetric {
+            metric_id: 0,
+            kind,
+            stage,
+            value,
+            sample_count,
+            computed_at: now,
+        });
     };
-    ctx.db.blockchain_record().insert(rec);
-    Ok(())
+
+    for (i, stage) in PIPELINE_STAGES.iter().enumerate() {
+        let samples = days_in_stage[i].len() as u64;
+        if let Some(m) = median(&mut days_in_stage[i]) {
+            insert(PipelineMetricKind::MedianDaysInStage, Some(stage.clone()), m, samples);
+        }
+        // Conversion is measured against the nearest earlier mandatory stage, so optional stages
+        // neither inflate nor shrink the rate of the stage after them
+        let prev = PIPELINE_STAGES[..i].iter().rposition(|s| !is_optional_stage(s));
+        if let Some(p) = prev.filter(|p| reached[*p] > 0) {
+            let rate = reached[i] as f64 / reached[p] as f64;
+            insert(PipelineMetricKind::StageConversionRate, Some(stage.clone()), rate, reached[p]);
+        }
+    }
+    let samples = idea_to_filing.len() as u64;
+    if let Some(m) = median(&mut idea_to_filing) {
+        insert(PipelineMetricKind::MedianIdeaToFilingDays, None, m, samples);
+    }
+    let samples = filing_to_grant.len() as u64;
+    if let Some(m) = median(&mut filing_to_grant) {
+        insert(PipelineMetricKind::MedianFilingToGrantDays, None, m, samples);
+    }
+    insert(PipelineMetricKind::StalledCount, None, stalled as f64, open_apps);
+}
+
+fn compute_doc_gen_metrics(ctx: &ReducerContext) {
+    let stale: Vec<u64> = ctx.db.doc_gen_metric().iter().map(|m| m.metric_id).collect();
+    for id in stale {
+        ctx.db.doc_gen_metric().metric_id().delete(id);
+    }
+
+    let mut by_type: Vec<(DocType, Vec<f64>, u64)> = Vec::new();
+    for attempt in ctx.db.doc_gen_attempt().iter() {
+        let (Some(outcome), Some(duration_ms)) = (attempt.outcome, attempt.duration_ms) else {
+            continue;
+        };
+        let idx = match by_type.iter().position(|(t, ..)| *t == attempt.doc_type) {
+            Some(i) => i,
+            None => {
+                by_type.push((attempt.doc_type.clone(), Vec::new(), 0));
+                by_type.len() - 1
+            }
+        };
+        let entry = &mut by_type[idx];
+        entry.1.push(duration_ms as f64);
+        if outcome == DocGenStatus::Failed {
+            entry.2 += 1;
+        }
+    }
+
+    for (doc_type, mut durations, failed) in by_type {
+        let finished = durations.len() as u64;
+        let avg = durations.iter().sum::<f64>() / finished as f64;
+        let med = median(&mut durations).unwrap_or(0.0);
+        ctx.db.doc_gen_metric().insert(DocGenMetric {
+            metric_id: 0,
+            doc_type,
+            finished_attempts: finished,
+            failed_attempts: failed,
+            failure_rate: failed as f64 / finished as f64,
+            avg_duration_ms: avg,
+            median_duration_ms: med,
+            computed_at: ctx.timestamp,
+        });
+    }
 }
 
 #[reducer]
-pub fn start_collab_session(ctx: &ReducerContext, title: String) -> Result<(), String> {
+pub fn analytics_tick(ctx: &ReducerContext, _info: AnalyticsSchedule) -> Result<(), String> {
+    // Security: only scheduler triggers this reducer
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer 'analytics_tick' may only be invoked by scheduling.".into());
+    }
+
     let now = ctx.timestamp;
-    let session = CollaborationSession {
-        session_id: 0,
-        title: title.clone(),
-        created_by: ctx.sender,
-        status: CollabStatus::Active,
-        started_at: now,
-        ended_at: now,
-        ended: false,
-    };
-    let inserted = ctx.db.collab_session().insert(session);
-    let sid = inserted.session_id;
-    let participant = SessionParticipant {
-        row_id: 0,
-        session_id: sid,
-        participant: ctx.sender,
-        joined_at: now,
-        active: true,
-    };
-    ctx.db.collab_participant().insert(participant);
-    spacetimedb::log::info!("Collaboration session started id={} title={}", sid, title);
+
+    // Aggregate per public segment, per holder and per organization
+    let mut by_segment: HashMap<String, TrendCounts> = HashMap::new();
+    by_segment.insert("global".to_string(), TrendCounts::default());
+    let mut by_holder: HashMap<Identity, TrendCounts> = HashMap::new();
+    let mut by_org: HashMap<String, TrendCounts> = HashMap::new();
+    let mut app_keys: HashMap<u64, TrendKeys> = HashMap::new();
+
+    for app in ctx.db.patent_application().iter() {
+        let segments = application_segments(ctx, &app);
+        let granted_at = application_granted_at(ctx, &app);
+        let holders = application_holders(ctx, &app);
+        let mut orgs: Vec<String> = Vec::new();
+        for h in &holders {
+            for org in organizations_of(ctx, *h) {
+                if !orgs.contains(&org) {
+                    orgs.push(org);
+                }
+            }
+        }
+        for seg in &segments {
+            by_segment.entry(seg.clone()).or_default().add_application(now, &app, granted_at);
+        }
+
+        // Jurisdiction segments count each office filing with its own per-office status
+        let mut segments = segments;
+        for f in ctx.db.jurisdiction_filing().application_id().filter(app.application_id) {
+            let seg = office_segment(&f.office).to_string();
+            // A jurisdiction row's updated_at only moves when its status changes
+            let granted_at_office = (f.status == PatentStatus::Granted).then_some(f.updated_at);
+            by_segment.entry(seg.clone()).or_default().add_filing(now, f.filing_date, &f.status, granted_at_office);
+            if !segments.contains(&seg) {
+                segments.push(seg);
+            }
+        }
+
+        for h in &holders {
+            by_holder.entry(*h).or_default().add_application(now, &app, granted_at);
+        }
+        for org in &orgs {
+            by_org.entry(org.clone()).or_default().add_application(now, &app, granted_at);
+        }
+        app_keys.insert(app.application_id, TrendKeys { segments, holders, orgs });
+    }
+
+    for a in ctx.db.infringement_alert().iter() {
+        by_segment.entry("global".to_string()).or_default().add_alert(now, &a);
+        if let Some(keys) = app_keys.get(&a.application_id) {
+            for seg in keys.segments.iter().filter(|s| s.as_str() != "global") {
+                by_segment.entry(seg.clone()).or_default().add_alert(now, &a);
+            }
+            for h in &keys.holders {
+                by_holder.entry(*h).or_default().add_alert(now, &a);
+            }
+            for org in &keys.orgs {
+                by_org.entry(org.clone()).or_default().add_alert(now, &a);
+            }
+        }
+    }
+
+    let mut active_sessions: u64 = 0;
+    for s in ctx.db.collab_session().iter() {
+        if s.status == CollabStatus::Active {
+            active_sessions += 1;
+        }
+    }
+
+    // Insert snapshots
+    insert_snapshot(ctx, "global", TrendMetric::ActiveSessionCount, active_sessions, "current");
+    for (segment, counts) in &by_segment {
+        for (metric, value, window) in counts.rows() {
+            insert_snapshot(ctx, segment, metric, value, window);
+        }
+    }
+
+    for (holder, counts) in &by_holder {
+        replace_portfolio_snapshots(ctx, *holder, "owner", counts);
+    }
+
+    // Organization snapshots are addressed to every verified member of the organization
+    for (org, counts) in &by_org {
+        let scope = format!("org:{}", org);
+        for m in ctx.db.organization_member().organization().filter(org) {
+            replace_portfolio_snapshots(ctx, m.member, &scope, counts);
+        }
+    }
+
+    // Viewers who no longer hold anything or left an organization keep no private rows
+    let stale: Vec<u64> = ctx
+        .db
+        .portfolio_trend_snapshot()
+        .iter()
+        .filter(|s| s.computed_at != now)
+        .map(|s| s.snapshot_id)
+        .collect();
+    for id in stale {
+        ctx.db.portfolio_trend_snapshot().snapshot_id().delete(id);
+    }
+
+    compute_pipeline_metrics(ctx);
+    compute_doc_gen_metrics(ctx);
+
+    spacetimedb::log::debug!("analytics_tick completed");
     Ok(())
 }
 
-#[reducer]
-pub fn join_collab_session(ctx: &ReducerContext, session_id: u64) -> Result<(), String> {
-    if let Some(sess) = ctx.db.collab_session().session_id().find(session_id) {
-        if sess.status != CollabStatus::Active {
-            return Err("Session is not active".into());
-        }
-        // Check if already participant
-        for p in ctx.db.collab_participant().iter() {
-            if p.session_id == session_id && p.participant == ctx.sender && p.active {
-                return Ok(());
+// Raw snapshots are kept for 48h, hourly rollups for 30 days, daily rollups indefinitely
+const RAW_SNAPSHOT_RETENTION_SECS: u64 = 48 * 3_600;
+const HOURLY_ROLLUP_RETENTION_SECS: u64 = 30 * 86_400;
+
+fn bucket_start(at: Timestamp, bucket_secs: u64) -> Timestamp {
+    let bucket_micros = (bucket_secs * 1_000_000) as i64;
+    let micros = at.to_micros_since_unix_epoch();
+    Timestamp::from_micros_since_unix_epoch(micros - micros.rem_euclid(bucket_micros))
+}
+
+fn older_than(now: Timestamp, at: Timestamp, secs: u64) -> bool {
+    now.to_micros_since_unix_epoch() - at.to_micros_since_unix_epoch() > (secs * 1_000_000) as i64
+}
+
+// Folds `incoming` into the matching rollup row, or inserts it when the bucket has none yet
+fn merge_rollup(ctx: &ReducerContext, incoming: MarketTrendRollup) {
+    let existing = ctx.db.market_trend_rollup().segment().filter(&incoming.segment).find(|r| {
+        r.metric == incoming.metric
+            && r.window == incoming.window
+            && r.resolution == incoming.resolution
+            && r.bucket_start == incoming.bucket_start
+    });
+    match existing {
+        Some(mut r) => {
+            let total = r.sample_count + incoming.sample_count;
+            r.avg = (r.avg * r.sample_count as f64 + incoming.avg * incoming.sample_count as f64) / total as f64;
+            r.min = r.min.min(incoming.min);
+            r.max = r.max.max(incoming.max);
+            if incoming.last_at >= r.last_at {
+                r.last = incoming.last;
+                r.last_at = incoming.last_at;
             }
+            r.sample_count = total;
+            ctx.db.market_trend_rollup().rollup_id().update(r);
+        }
+        None => {
+            ctx.db.market_trend_rollup().insert(incoming);
         }
-        let row = SessionParticipant {
-            row_id: 0,
-            session_id,
-            participant: ctx.sender,
-            joined_at: ctx.timestamp,
-            active: true,
-        };
-        ctx.db.collab_participant().insert(row);
-        Ok(())
-    } else {
-        Err("Session not found".into())
     }
 }
 
 #[reducer]
-pub fn leave_collab_session(ctx: &ReducerContext, session_id: u64) -> Result<(), String> {
-    // Mark participant inactive
-    let mut to_update: Vec<u64> = Vec::new();
-    for p in ctx.db.collab_participant().iter() {
-        if p.session_id == session_id && p.participant == ctx.sender && p.active {
-            to_update.push(p.row_id);
-        }
+pub fn compaction_tick(ctx: &ReducerContext, _info: CompactionSchedule) -> Result<(), String> {
+    // Security: only scheduler triggers this reducer
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer 'compaction_tick' may only be invoked by scheduling.".into());
     }
-    for id in to_update {
-        if let Some(mut row) = ctx.db.collab_participant().row_id().find(id) {
-            row.active = false;
-            ctx.db.collab_participant().row_id().update(row);
-        }
+
+    let now = ctx.timestamp;
+
+    // Raw snapshots past retention become hourly rollups
+    let expired: Vec<MarketTrendSnapshot> = ctx
+        .db
+        .market_trend_snapshot()
+        .iter()
+        .filter(|s| older_than(now, s.computed_at, RAW_SNAPSHOT_RETENTION_SECS))
+        .collect();
+    let raw_count = expired.len();
+    for snap in expired {
+        merge_rollup(
+            ctx,
+            MarketTrendRollup {
+                rollup_id: 0,
+                segment: snap.segment.clone(),
+                metric: snap.metric.clone(),
+                window: snap.window.clone(),
+                resolution: RollupResolution::Hourly,
+                bucket_start: bucket_start(snap.computed_at, 3_600),
+                min: snap.value,
+                max: snap.value,
+                avg: snap.value,
+                last: snap.value,
+                last_at: snap.computed_at,
+                sample_count: 1,
+            },
+        );
+        ctx.db.market_trend_snapshot().snapshot_id().delete(snap.snapshot_id);
     }
+
+    // Hourly rollups past retention become daily rollups
+    let expired: Vec<MarketTrendRollup> = ctx
+        .db
+        .market_trend_rollup()
+        .iter()
+        .filter(|r| r.resolution == RollupResolution::Hourly && older_than(now, r.bucket_start, HOURLY_ROLLUP_RETENTION_SECS))
+        .collect();
+    let hourly_count = expired.len();
+    for hourly in expired {
+        ctx.db.market_trend_rollup().rollup_id().delete(hourly.rollup_id);
+        let daily = MarketTrendRollup {
+            rollup_id: 0,
+            resolution: RollupResolution::Daily,
+            bucket_start: bucket_start(hourly.bucket_start, 86_400),
+            ..hourly
+        };
+        merge_rollup(ctx, daily);
+    }
+
+    spacetimedb::log::debug!(
+        "compaction_tick completed raw_compacted={} hourly_compacted={}",
+        raw_count,
+        hourly_count
+    );
     Ok(())
 }
 
 #[reducer]
-pub fn close_collab_session(ctx: &ReducerContext, session_id: u64) -> Result<(), String> {
-    if let Some(mut sess) = ctx.db.collab_session().session_id().find(session_id) {
-        if sess.created_by != ctx.sender {
-            return Err("Only the creator can close the session".into());
+pub fn docket_tick(ctx: &ReducerContext, _info: DocketSchedule) -> Result<(), String> {
+    // Security: only scheduler triggers this reducer
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer 'docket_tick' may only be invoked by scheduling.".into());
+    }
+
+    let app_ids: Vec<u64> = ctx.db.patent_application().iter().map(|a| a.application_id).collect();
+    for id in app_ids {
+        recompute_deadlines(ctx, id);
+    }
+
+    // Send the tightest reminder whose lead time has been reached; looser ones that were
+    // skipped (e.g., a deadline docketed 20 days out) are not sent after the fact. Reminders
+    // count per due date, so a deadline moved by an extension is reminded again.
+    let now = ctx.timestamp;
+    let leads = reminder_lead_days(ctx);
+    let mut sent: u64 = 0;
+    let open: Vec<Deadline> = ctx.db.deadline().iter().filter(|d| !d.completed).collect();
+    for d in open {
+        let Some(app) = ctx.db.patent_application().application_id().find(d.application_id) else {
+            continue;
+        };
+        let Some(lead) = reminder_lead_due(now, d.due_at, &leads) else {
+            continue;
+        };
+        let sent_before: Vec<(Timestamp, u32)> =
+            ctx.db.deadline_reminder().deadline_id().filter(d.deadline_id).map(|r| (r.due_at, r.lead_days)).collect();
+        if reminder_already_sent(&sent_before, d.due_at, lead) {
+            continue;
         }
-        sess.status = CollabStatus::Closed;
-        sess.ended = true;
-        sess.ended_at = ctx.timestamp;
-        ctx.db.collab_session().session_id().update(sess);
-        Ok(())
-    } else {
-        Err("Session not found".into())
+        ctx.db.deadline_reminder().insert(DeadlineReminder {
+            reminder_id: 0,
+            deadline_id: d.deadline_id,
+            recipient: app.owner,
+            lead_days: lead,
+            due_at: d.due_at,
+            created_at: now,
+            acknowledged: false,
+        });
+        if lead == 0 {
+            spacetimedb::log::warn!("Deadline {} for app_id={} was missed", d.deadline_id, d.application_id);
+        }
+        sent += 1;
     }
+
+    spacetimedb::log::debug!("docket_tick completed reminders_sent={}", sent);
+    Ok(())
 }
 
 #[reducer]
-pub fn add_to_portfolio(
-    ctx: &ReducerContext,
-    application_id: u64,
-    role: PortfolioRole,
-) -> Result<(), String> {
-    // Avoid duplicates (owner+application_id+role)
-    for e in ctx.db.portfolio_entry().iter() {
-        if e.owner == ctx.sender && e.application_id == application_id && e.role == role {
-            return Ok(());
+pub fn maintenance_tick(ctx: &ReducerContext, _info: MaintenanceSchedule) -> Result<(), String> {
+    // Security: only scheduler triggers this reducer
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer 'maintenance_tick' may only be invoked by scheduling.".into());
+    }
+
+    // A maintenance fee still unpaid once its grace period closes lapses the patent
+    let now = ctx.timestamp;
+    let mut lapsed: u64 = 0;
+    let overdue: Vec<MaintenanceFee> = ctx
+        .db
+        .maintenance_fee()
+        .iter()
+        .filter(|f| f.paid_at.is_none() && now > f.grace_ends_at)
+        .collect();
+    for fee in overdue {
+        let Some(mut grant) = ctx.db.grant_record().application_id().find(fee.application_id) else {
+            continue;
+        };
+        if grant.lapsed {
+            continue;
         }
+        grant.lapsed = true;
+        grant.lapsed_at = Some(now);
+        ctx.db.grant_record().application_id().update(grant);
+        record_event(ctx, fee.application_id, ApplicationEventPayload::PatentLapsed(fee.fee_id));
+        recompute_deadlines(ctx, fee.application_id);
+        spacetimedb::log::warn!("Patent for app_id={} lapsed: {} maintenance fee unpaid", fee.application_id, fee.label);
+        lapsed += 1;
     }
-    let row = PatentPortfolioEntry {
-        entry_id: 0,
-        owner: ctx.sender,
-        application_id,
-        role: role.clone(),
-        added_at: ctx.timestamp,
-    };
-    ctx.db.portfolio_entry().insert(row);
+
+    spacetimedb::log::debug!("maintenance_tick completed lapsed={}", lapsed);
     Ok(())
 }
 
 #[reducer]
-pub fn connect_inventor(ctx: &ReducerContext, target: Identity) -> Result<(), String> {
-    if target == ctx.sender {
-        return Err("Cannot connect to self".into());
+pub fn reclaim_doc_job_leases(ctx: &ReducerContext, _info: DocLeaseSchedule) -> Result<(), String> {
+    // Security: only scheduler triggers this reducer
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer 'reclaim_doc_job_leases' may only be invoked by scheduling.".into());
     }
-    // Prevent duplicates in either direction while pending/connected
-    for c in ctx.db.inventor_connection().iter() {
-        let pair = (c.a == ctx.sender && c.b == target) || (c.a == target && c.b == ctx.sender);
-        if pair && (c.status == ConnectionStatus::Pending || c.status == ConnectionStatus::Connected) {
-            return Ok(());
+
+    // A worker that stopped heartbeating counts as a failed attempt
+    let now = ctx.timestamp;
+    let expired: Vec<DocJob> = ctx
+        .db
+        .doc_job()
+        .iter()
+        .filter(|j| j.status == DocJobStatus::Leased && j.lease_expires_at.is_some_and(|at| at < now))
+        .collect();
+    // Each job is handled on its own so one bad row cannot roll back the whole sweep
+    let mut reclaimed = 0;
+    for job in expired {
+        let job_id = job.job_id;
+        match retry_or_fail_job(ctx, job, "Lease expired".to_string()) {
+            Ok(()) => reclaimed += 1,
+            Err(e) => spacetimedb::log::warn!("Could not reclaim doc job {}: {}", job_id, e),
         }
     }
-    let row = InventorConnection {
-        connection_id: 0,
-        a: ctx.sender,
-        b: target,
-        status: ConnectionStatus::Pending,
-        created_at: ctx.timestamp,
-        updated_at: ctx.timestamp,
-    };
-    ctx.db.inventor_connection().insert(row);
+
+    spacetimedb::log::debug!("reclaim_doc_job_leases completed reclaimed={}", reclaimed);
     Ok(())
 }
 
 #[reducer]
-pub fn respond_connection(ctx: &ReducerContext, connection_id: u64, accept: bool) -> Result<(), String> {
-    if let Some(mut conn) = ctx.db.inventor_connection().connection_id().find(connection_id) {
-        if conn.b != ctx.sender && conn.a != ctx.sender {
-            return Err("Not authorized to respond to this connection".into());
+pub fn merkle_batch_tick(ctx: &ReducerContext, _info: MerkleSchedule) -> Result<(), String> {
+    // Security: only scheduler triggers this reducer
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer 'merkle_batch_tick' may only be invoked by scheduling.".into());
+    }
+
+    // Pending digests are opted-in notarizations that are neither anchored nor batched yet
+    let mut pending: Vec<Notarization> = ctx
+        .db
+        .notarization()
+        .batch_requested()
+        .filter(true)
+        .filter(|n| n.record_id.is_none() && n.batch_id.is_none())
+        .collect();
+    pending.sort_by_key(|n| n.notarization_id);
+    pending.truncate(MAX_BATCH_LEAVES);
+
+    let mut hashed: Vec<(Notarization, [u8; 32])> = Vec::new();
+    for n in pending {
+        match merkle_leaf_hash(&n.digest) {
+            Some(h) => hashed.push((n, h)),
+            None => spacetimedb::log::warn!("Skipping notarization {} with malformed digest", n.notarization_id),
         }
-        conn.status = if accept { ConnectionStatus::Connected } else { ConnectionStatus::Rejected };
-        conn.updated_at = ctx.timestamp;
-        let status_log = format!("{:?}", conn.status);
-        ctx.db.inventor_connection().connection_id().update(conn);
-        spacetimedb::log::info!("Connection {} updated to {}", connection_id, status_log);
-        Ok(())
-    } else {
-        Err("Connection not found".into())
     }
+    if hashed.is_empty() {
+        spacetimedb::log::debug!("merkle_batch_tick completed leaves=0");
+        return Ok(());
+    }
+
+    let leaf_hashes: Vec<[u8; 32]> = hashed.iter().map(|(_, h)| *h).collect();
+    let (root, proofs) = build_merkle_tree(&leaf_hashes);
+    let leaves: Vec<MerkleLeaf> = hashed
+        .iter()
+        .zip(proofs)
+        .map(|((n, h), proof)| MerkleLeaf {
+            notarization_id: n.notarization_id,
+            application_id: n.application_id,
+            leaf_hash: hex_encode(h),
+            proof,
+        })
+        .collect();
+    let count = leaves.len();
+    let batch = ctx.db.merkle_batch().insert(MerkleBatch {
+        batch_id: 0,
+        root: hex_encode(&root),
+        leaves,
+        created_at: ctx.timestamp,
+        tx_hash: None,
+        network: String::new(),
+        anchored_at: None,
+    });
+
+    for (mut n, _) in hashed {
+        n.batch_id = Some(batch.batch_id);
+        ctx.db.notarization().notarization_id().update(n);
+    }
+
+    spacetimedb::log::info!("merkle_batch_tick created batch {} root={} leaves={}", batch.batch_id, batch.root, count);
+    Ok(())
 }
 
-#[reducer]
-pub fn update_stage_progress(
-    ctx: &ReducerContext,
-    application_id: u64,
-    stage: Stage,
-    percent: u8,
-) -> Result<(), String> {
-    // Upsert StageProgress per (application_id, stage)
-    let mut existing_id: Option<u64> = None;
-    for sp in ctx.db.stage_progress().iter() {
-        if sp.application_id == application_id && sp.stage == stage {
-            existing_id = Some(sp.progress_id);
-            break;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn portfolio_archive_skips_applications_held_only_through_self_assigned_roles() {
+        // A stranger who added someone else's application as Viewer/Licensee gets none of its content
+        let stranger = archive_application_roles(&[], &[], &[], &[(7, PortfolioRole::Viewer), (8, PortfolioRole::Licensee)]);
+        assert!(stranger.is_empty());
+
+        let holder = archive_application_roles(&[1], &[2], &[1, 3], &[(1, PortfolioRole::Owner), (9, PortfolioRole::Viewer)]);
+        let ids: Vec<u64> = holder.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(holder[0].1, vec!["Owner", "Inventor", "Portfolio:Owner"]);
+        assert_eq!(holder[1].1, vec!["CoOwner"]);
+        assert_eq!(holder[2].1, vec!["Inventor"]);
+    }
+
+    fn sample_application() -> St96Application {
+        let claims_text = "1. A widget comprising a <housing> & a \"lid\",\n   wherein the lid > 2 mm.\n2) The widget of claim 1, wherein\nthe housing is sealed.";
+        St96Application {
+            identification: Some(St96Identification {
+                office: PatentOffice::Uspto,
+                application_number: "17/123,456".to_string(),
+                filing_date: parse_date("2023-04-05").unwrap(),
+            }),
+            title: "Widget & <Housing> \"Pro\"".to_string(),
+            abstract_text: "A widget.\nIt has a lid & a <seal>.".to_string(),
+            claims: split_claims(claims_text),
+            inventors: vec![
+                St96Inventor { sequence: 1, name: "Ada O'Neil & Co".to_string(), email: "ada@example.com".to_string() },
+                St96Inventor { sequence: 2, name: "Lin <Li>".to_string(), email: "lin@example.com".to_string() },
+            ],
+            citations: vec![
+                St96Citation {
+                    publication_number: "US1234567B2".to_string(),
+                    text: "Prior widget with \"lid\"".to_string(),
+                    url: "https://patents.google.com/patent/US1234567B2/en?q=a&b=c".to_string(),
+                    source: "google_patents".to_string(),
+                },
+                St96Citation {
+                    publication_number: String::new(),
+                    text: "Smith et al., Lids < Seals".to_string(),
+                    url: String::new(),
+                    source: "manual".to_string(),
+                },
+            ],
         }
     }
-    if let Some(pid) = existing_id {
-        if let Some(mut row) = ctx.db.stage_progress().progress_id().find(pid) {
-            row.percent = percent;
-            row.updated_at = ctx.timestamp;
-            ctx.db.stage_progress().progress_id().update(row);
+
+    #[test]
+    fn st96_round_trip_preserves_fields() {
+        let doc = sample_application();
+        let parsed = parse_st96_application(&st96_application_xml(&doc)).unwrap();
+
+        assert_eq!(parsed.identification, doc.identification);
+        assert_eq!(parsed.title, doc.title);
+        assert_eq!(parsed.abstract_text, doc.abstract_text);
+        assert_eq!(parsed.claims.len(), 2);
+        assert_eq!(parsed.claims, doc.claims);
+        assert_eq!(parsed.claims.join("\n"), doc.claims.join("\n"));
+        assert_eq!(parsed.inventors.len(), 2);
+        for (got, want) in parsed.inventors.iter().zip(&doc.inventors) {
+            assert_eq!(got.sequence, want.sequence);
+            assert_eq!(got.name, want.name);
+            assert_eq!(got.email, want.email);
+        }
+        assert_eq!(parsed.citations.len(), 2);
+        for (got, want) in parsed.citations.iter().zip(&doc.citations) {
+            assert_eq!(got.publication_number, want.publication_number);
+            assert_eq!(got.text, want.text);
+            assert_eq!(got.url, want.url);
+            assert_eq!(got.source, want.source);
         }
-    } else {
-        let row = StageProgress {
-            progress_id: 0,
-            application_id,
-            stage: stage.clone(),
-            percent,
-            updated_at: ctx.timestamp,
-        };
-        ctx.db.stage_progress().insert(row);
     }
 
-    // Touch application last_updated if exists
-    if let Some(mut app) = ctx.db.patent_application().application_id().find(application_id) {
-        app.last_updated = ctx.timestamp;
-        ctx.db.patent_application().application_id().update(app);
+    #[test]
+    fn split_claims_keeps_continuation_lines() {
+        let claims = split_claims("1. First\n  continued\n2. Second\n10) Tenth");
+        assert_eq!(claims, vec!["1. First\n  continued", "2. Second", "10) Tenth"]);
     }
 
-    Ok(())
-}
+    #[test]
+    fn st96_import_rejects_bad_input() {
+        assert!(parse_st96_application("<Other/>").is_err());
+        assert!(parse_st96_application("<pat:PatentApplication xmlns:pat=\"x\"></pat:PatentApplication>").is_err());
+        assert!(parse_st96_application("<PatentApplication><InventionTitle>T</Wrong></PatentApplication>").is_err());
 
-#[reducer]
-pub fn resolve_alert(ctx: &ReducerContext, alert_id: u64) -> Result<(), String> {
-    if let Some(mut alert) = ctx.db.infringement_alert().alert_id().find(alert_id) {
-        alert.resolved = true;
-        ctx.db.infringement_alert().alert_id().update(alert);
-        Ok(())
-    } else {
-        Err("Alert not found".into())
+        let deep = format!(
+            "<PatentApplication><InventionTitle>T</InventionTitle>{}{}</PatentApplication>",
+            "<a>".repeat(MAX_XML_DEPTH),
+            "</a>".repeat(MAX_XML_DEPTH)
+        );
+        assert!(parse_st96_application(&deep).unwrap_err().contains("nesting"));
+
+        let huge = format!("<PatentApplication><InventionTitle>{}</InventionTitle></PatentApplication>", "x".repeat(MAX_XML_BYTES));
+        assert!(parse_st96_application(&huge).unwrap_err().contains("exceeds"));
     }
-}
 
-// ---------- Scheduled Reducers ----------
+    #[test]
+    fn imported_maintenance_fees_keep_open_windows_unpaid() {
+        let grant = parse_date("2015-03-10").unwrap();
+        let now = parse_date("2024-01-01").unwrap();
 
-#[reducer]
-pub fn monitor_tick(ctx: &ReducerContext, _info: MonitoringSchedule) -> Result<(), String> {
-    // Security: only scheduler triggers this reducer
-    if ctx.sender != ctx.identity() {
-        return Err("Reducer 'monitor_tick' may only be invoked by scheduling.".into());
+        let fees = imported_maintenance_fees(grant, Some("2018-09-01;2022-09-01"), now).unwrap();
+        assert_eq!(fees[0].paid_at, Some(parse_date("2018-09-01").unwrap()));
+        assert_eq!(fees[1].paid_at, Some(parse_date("2022-09-01").unwrap()));
+        // 11.5-year window has not opened yet
+        assert_eq!(fees[2].paid_at, None);
+        assert!(imported_maintenance_fees(grant, Some("2018-09-01;2022-09-01;"), now).is_ok());
+
+        // The 7.5-year window closed in March 2023; leaving it out must not count as paid
+        let missing = imported_maintenance_fees(grant, Some("2018-09-01"), now).err().unwrap_or_default();
+        assert!(missing.contains("7.5"), "{}", missing);
+        assert!(imported_maintenance_fees(grant, None, now).is_err());
+
+        assert!(imported_maintenance_fees(grant, Some("2016-01-01"), now).is_err());
+        assert!(imported_maintenance_fees(grant, Some("2018-09-01;2022-09-01;2026-09-01;2027-01-01"), now).is_err());
     }
 
-    // Simple automated monitoring demo: ensure each Submitted/Examination app has a heartbeat alert once
-    for app in ctx.db.patent_application().iter() {
-        if app.status == PatentStatus::Submitted || app.status == PatentStatus::Examination {
-            let mut has_heartbeat = false;
-            for a in ctx.db.infringement_alert().iter() {
-                if a.application_id == app.application_id && a.alert_type == "monitor_heartbeat" {
-                    has_heartbeat = true;
-                    break;
-                }
-            }
-            if !has_heartbeat {
-                let alert = InfringementAlert {
-                    alert_id: 0,
-                    application_id: app.application_id,
-                    alert_type: "monitor_heartbeat".to_string(),
-                    severity: AlertSeverity::Low,
-                    description: "Automated monitoring check executed".to_string(),
-                    detected_at: ctx.timestamp,
-                    resolved: true,
-                };
-                ctx.db.infringement_alert().insert(alert);
+    #[test]
+    fn alert_trends_skip_monitor_heartbeats() {
+        let now = Timestamp::from_micros_since_unix_epoch(100 * 86_400 * 1_000_000);
+        let alert = |alert_type: &str, days_ago: i64, resolved: bool| InfringementAlert {
+            alert_id: 0,
+            application_id: 1,
+            alert_type: alert_type.to_string(),
+            severity: AlertSeverity::Low,
+            description: String::new(),
+            detected_at: Timestamp::from_micros_since_unix_epoch(now.to_micros_since_unix_epoch() - days_ago * 86_400 * 1_000_000),
+            resolved,
+        };
+        let mut counts = TrendCounts::default();
+        for a in [
+            alert(MONITOR_HEARTBEAT_ALERT, 0, true),
+            alert(MONITOR_HEARTBEAT_ALERT, 3, true),
+            alert("similar_claims", 0, false),
+            alert("similar_claims", 3, true),
+            alert("similar_claims", 20, false),
+            alert("similar_claims", 60, false),
+        ] {
+            counts.add_alert(now, &a);
+        }
+        assert_eq!(counts.open_alerts, 3);
+        assert_eq!(counts.window_alerts, [1, 2, 3]);
+    }
+
+    fn merkle_leaves(n: usize) -> Vec<[u8; 32]> {
+        (0..n).map(|i| merkle_leaf_hash(&sha256_hex(format!("revision {}", i).as_bytes())).unwrap()).collect()
+    }
+
+    #[test]
+    fn merkle_proofs_round_trip_for_any_leaf_count() {
+        for n in [1, 2, 3, 5, 7, 8, 9] {
+            let leaves = merkle_leaves(n);
+            let (root, proofs) = build_merkle_tree(&leaves);
+            assert_eq!(proofs.len(), n);
+            for (leaf, proof) in leaves.iter().zip(&proofs) {
+                assert_eq!(merkle_root_from_proof(*leaf, proof), Some(root), "{} leaves", n);
             }
         }
+
+        // A single leaf is its own root with an empty proof
+        let one = merkle_leaves(1);
+        let (root, proofs) = build_merkle_tree(&one);
+        assert_eq!(root, one[0]);
+        assert!(proofs[0].is_empty());
+
+        // With three leaves the unpaired third one moves up unchanged
+        let three = merkle_leaves(3);
+        let (root, proofs) = build_merkle_tree(&three);
+        assert_eq!(root, merkle_node_hash(&merkle_node_hash(&three[0], &three[1]), &three[2]));
+        assert_eq!(proofs[2].len(), 1);
     }
 
-    spacetimedb::log::debug!("monitor_tick completed");
-    Ok(())
-}
+    #[test]
+    fn tampered_merkle_proofs_do_not_reach_the_root() {
+        let leaves = merkle_leaves(5);
+        let (root, proofs) = build_merkle_tree(&leaves);
 
-#[reducer]
-pub fn analytics_tick(ctx: &ReducerContext, _info: AnalyticsSchedule) -> Result<(), String> {
-    // Security: only scheduler triggers this reducer
-    if ctx.sender != ctx.identity() {
-        return Err("Reducer 'analytics_tick' may only be invoked by scheduling.".into());
+        let mut flipped_side = proofs[1].clone();
+        flipped_side[0].sibling_is_left = !flipped_side[0].sibling_is_left;
+        assert_ne!(merkle_root_from_proof(leaves[1], &flipped_side), Some(root));
+
+        let mut other_sibling = proofs[1].clone();
+        other_sibling[0].sibling = hex_encode(&leaves[3]);
+        assert_ne!(merkle_root_from_proof(leaves[1], &other_sibling), Some(root));
+
+        let mut truncated = proofs[1].clone();
+        truncated.pop();
+        assert_ne!(merkle_root_from_proof(leaves[1], &truncated), Some(root));
+
+        // A proof only works for its own leaf
+        assert_ne!(merkle_root_from_proof(leaves[2], &proofs[1]), Some(root));
+
+        let mut malformed = proofs[1].clone();
+        malformed[0].sibling = "zz".to_string();
+        assert_eq!(merkle_root_from_proof(leaves[1], &malformed), None);
     }
 
-    // Compute simple global aggregates
-    let mut total_apps: u64 = 0;
-    let mut granted_apps: u64 = 0;
-    for app in ctx.db.patent_application().iter() {
-        total_apps += 1;
-        if app.status == PatentStatus::Granted {
-            granted_apps += 1;
-        }
+    fn day(date: &str) -> Timestamp {
+        parse_date(date).unwrap()
     }
 
-    let mut active_sessions: u64 = 0;
-    for s in ctx.db.collab_session().iter() {
-        if s.status == CollabStatus::Active {
-            active_sessions += 1;
-        }
+    #[test]
+    fn add_months_clamps_to_the_end_of_shorter_months() {
+        assert_eq!(add_months(day("2023-01-31"), 1), day("2023-02-28"));
+        assert_eq!(add_months(day("2024-01-31"), 1), day("2024-02-29"));
+        assert_eq!(add_months(day("2023-08-31"), 1), day("2023-09-30"));
+        assert_eq!(add_months(day("2023-11-30"), 3), day("2024-02-29"));
+        assert_eq!(add_months(day("2024-02-29"), 12), day("2025-02-28"));
+        assert_eq!(add_months(day("2023-12-15"), 1), day("2024-01-15"));
+        assert_eq!(add_months(day("2023-05-31"), 0), day("2023-05-31"));
+
+        // Time of day survives the shift
+        let noon = Timestamp::from_micros_since_unix_epoch(day("2023-01-31").to_micros_since_unix_epoch() + 12 * 3_600_000_000);
+        assert_eq!(add_months(noon, 1).to_micros_since_unix_epoch() - day("2023-02-28").to_micros_since_unix_epoch(), 12 * 3_600_000_000);
     }
 
-    let mut unresolved_alerts: u64 = 0;
-    for a in ctx.db.infringement_alert().iter() {
-        if !a.resolved {
-            unresolved_alerts += 1;
-        }
+    #[test]
+    fn office_action_and_maintenance_bar_dates() {
+        let oa = OfficeAction {
+            action_id: 1,
+            application_id: 1,
+            action_type: OfficeActionType::NonFinal,
+            mailed_at: day("2023-11-30"),
+            shortened_deadline: add_months(day("2023-11-30"), 3),
+            statutory_deadline: add_months(day("2023-11-30"), 6),
+            extension_months: 0,
+            extension_fee_cents: 0,
+            response_filed_at: None,
+            recorded_by: Identity::ZERO,
+            created_at: day("2023-12-01"),
+        };
+        assert_eq!(office_action_response_deadline(&oa), day("2024-02-29"));
+        assert_eq!(oa.statutory_deadline, day("2024-05-30"));
+        let extended = OfficeAction { extension_months: 2, ..oa };
+        assert_eq!(office_action_response_deadline(&extended), day("2024-04-30"));
+
+        let fees = maintenance_schedule(1, day("2016-08-31"));
+        assert_eq!(fees[0].window_opens_at, day("2019-08-31"));
+        assert_eq!(fees[0].due_at, day("2020-02-29"));
+        assert_eq!(fees[0].grace_ends_at, day("2020-08-31"));
+        assert_eq!(fees[2].grace_ends_at, day("2028-08-31"));
     }
 
-    let now = ctx.timestamp;
+    #[test]
+    fn reminders_fire_at_the_tightest_reached_lead_and_only_once_per_due_date() {
+        let leads = [1, 7, 30, 90];
+        let due = day("2024-06-30");
+        assert_eq!(reminder_lead_due(day("2024-01-01"), due, &leads), None);
+        assert_eq!(reminder_lead_due(day("2024-04-01"), due, &leads), Some(90));
+        assert_eq!(reminder_lead_due(day("2024-05-31"), due, &leads), Some(30));
+        // Docketed 20 days out: the 30-day reminder is sent right away, the 90-day one never
+        assert_eq!(reminder_lead_due(day("2024-06-10"), due, &leads), Some(30));
+        assert_eq!(reminder_lead_due(day("2024-06-23"), due, &leads), Some(7));
+        assert_eq!(reminder_lead_due(day("2024-06-30"), due, &leads), Some(1));
+        assert_eq!(reminder_lead_due(day("2024-07-01"), due, &leads), Some(0));
 
-    // Insert snapshots
-    ctx.db.market_trend_snapshot().insert(MarketTrendSnapshot {
-        snapshot_id: 0,
-        segment: "global".to_string(),
-        metric: TrendMetric::FilingCount,
-        value: total_apps as f64,
-        window: "all_time".to_string(),
-        computed_at: now,
-    });
+        let sent = [(due, 30)];
+        assert!(reminder_already_sent(&sent, due, 30));
+        assert!(reminder_already_sent(&sent, due, 90));
+        assert!(!reminder_already_sent(&sent, due, 7));
+        // An extension moves the due date, so the same lead fires again
+        assert!(!reminder_already_sent(&sent, day("2024-08-31"), 30));
+    }
 
-    ctx.db.market_trend_snapshot().insert(MarketTrendSnapshot {
-        snapshot_id: 0,
-        segment: "global".to_string(),
-        metric: TrendMetric::GrantCount,
-        value: granted_apps as f64,
-        window: "all_time".to_string(),
-        computed_at: now,
-    });
+    fn diff_sides(segments: &[DiffSegment]) -> (String, String) {
+        let side = |skip: DiffOp| segments.iter().filter(|s| s.op != skip).map(|s| s.text.as_str()).collect::<String>();
+        (side(DiffOp::Insert), side(DiffOp::Delete))
+    }
 
-    ctx.db.market_trend_snapshot().insert(MarketTrendSnapshot {
-        snapshot_id: 0,
-        segment: "global".to_string(),
-        metric: TrendMetric::ActiveSessionCount,
-        value: active_sessions as f64,
-        window: "current".to_string(),
-        computed_at: now,
-    });
+    #[test]
+    fn identical_documents_diff_to_one_equal_segment() {
+        let text = "1. A widget.\n2. The widget of claim 1.\n";
+        assert_eq!(diff_documents(text, text), vec![DiffSegment { op: DiffOp::Equal, text: text.to_string() }]);
+    }
 
-    ctx.db.market_trend_snapshot().insert(MarketTrendSnapshot {
-        snapshot_id: 0,
-        segment: "global".to_string(),
-        metric: TrendMetric::AlertCount,
-        value: unresolved_alerts as f64,
-        window: "current".to_string(),
-        computed_at: now,
-    });
+    #[test]
+    fn fully_replaced_documents_share_no_words() {
+        let (old, new) = ("alpha beta\ngamma\n", "delta epsilon\nzeta eta\n");
+        let segments = diff_documents(old, new);
+        assert_eq!(diff_sides(&segments), (old.to_string(), new.to_string()));
+        // Only whitespace can line up between the two versions
+        assert!(segments.iter().filter(|s| s.op == DiffOp::Equal).all(|s| s.text.trim().is_empty()));
+        let deleted: u32 = segments.iter().filter(|s| s.op == DiffOp::Delete).map(|s| count_words(&s.text)).sum();
+        assert_eq!(deleted, 3);
+    }
 
-    spacetimedb::log::debug!("analytics_tick completed");
-    Ok(())
+    #[test]
+    fn empty_documents_diff_to_pure_inserts_or_deletes() {
+        assert!(diff_documents("", "").is_empty());
+        assert_eq!(diff_documents("", "new text\n"), vec![DiffSegment { op: DiffOp::Insert, text: "new text\n".to_string() }]);
+        assert_eq!(diff_documents("old text", ""), vec![DiffSegment { op: DiffOp::Delete, text: "old text".to_string() }]);
+    }
+
+    #[test]
+    fn changed_words_are_isolated_within_a_line() {
+        let (old, new) = ("The lid is red.\nUnchanged line.\n", "The lid is blue.\nUnchanged line.\n");
+        let segments = diff_documents(old, new);
+        assert_eq!(diff_sides(&segments), (old.to_string(), new.to_string()));
+        assert_eq!(
+            segments,
+            vec![
+                DiffSegment { op: DiffOp::Equal, text: "The lid is ".to_string() },
+                DiffSegment { op: DiffOp::Delete, text: "red.".to_string() },
+                DiffSegment { op: DiffOp::Insert, text: "blue.".to_string() },
+                DiffSegment { op: DiffOp::Equal, text: "\nUnchanged line.\n".to_string() },
+            ]
+        );
+        assert_eq!(redline_html(&segments), "The lid is <del>red.</del><ins>blue.</ins>\nUnchanged line.\n");
+    }
+
+    #[test]
+    fn doc_gen_transitions_allow_regeneration_only_from_completed_to_in_progress() {
+        use DocGenStatus::*;
+        assert!(doc_gen_transition_allowed(&Completed, &InProgress));
+        assert!(!doc_gen_transition_allowed(&Completed, &Failed));
+        assert!(!doc_gen_transition_allowed(&Completed, &NotStarted));
+        assert!(!doc_gen_transition_allowed(&NotStarted, &Completed));
+        assert!(doc_gen_transition_allowed(&Failed, &InProgress));
+    }
+
+    #[test]
+    fn manual_doc_gen_changes_are_rejected_while_a_job_is_active() {
+        use DocGenStatus::*;
+        for (from, to) in [(InProgress, Completed), (InProgress, Failed), (Completed, InProgress), (Failed, InProgress)] {
+            assert!(manual_doc_gen_transition_allowed(&from, &to, false));
+            assert!(!manual_doc_gen_transition_allowed(&from, &to, true));
+        }
+        assert!(!manual_doc_gen_transition_allowed(&Completed, &Failed, false));
+    }
 }
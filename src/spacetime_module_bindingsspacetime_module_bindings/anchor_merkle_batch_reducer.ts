@@ -33,8 +33,8 @@ import {
   type SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
 
-export type AddBlockchainRecord = {
-  applicationId: bigint,
+export type AnchorMerkleBatch = {
+  batchId: bigint,
   txHash: string,
   network: string,
 };
@@ -42,25 +42,25 @@ export type AddBlockchainRecord = {
 /**
  * A namespace for generated helper functions.
  */
-export namespace AddBlockchainRecord {
+export namespace AnchorMerkleBatch {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
   */
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createProductType([
-      new ProductTypeElement("applicationId", AlgebraicType.createU64Type()),
+      new ProductTypeElement("batchId", AlgebraicType.createU64Type()),
       new ProductTypeElement("txHash", AlgebraicType.createStringType()),
       new ProductTypeElement("network", AlgebraicType.createStringType()),
     ]);
   }
 
-  export function serialize(writer: BinaryWriter, value: AddBlockchainRecord): void {
-    AddBlockchainRecord.getTypeScriptAlgebraicType().serialize(writer, value);
+  export function serialize(writer: BinaryWriter, value: AnchorMerkleBatch): void {
+    AnchorMerkleBatch.getTypeScriptAlgebraicType().serialize(writer, value);
   }
 
-  export function deserialize(reader: BinaryReader): AddBlockchainRecord {
-    return AddBlockchainRecord.getTypeScriptAlgebraicType().deserialize(reader);
+  export function deserialize(reader: BinaryReader): AnchorMerkleBatch {
+    return AnchorMerkleBatch.getTypeScriptAlgebraicType().deserialize(reader);
   }
 
 }
@@ -41,6 +41,11 @@ export namespace DocType {
   export type Claims = { tag: "Claims" };
   export type Drawings = { tag: "Drawings" };
   export type Abstract = { tag: "Abstract" };
+  export type InventorDeclaration = { tag: "InventorDeclaration" };
+  export type ApplicationDataSheet = { tag: "ApplicationDataSheet" };
+  export type InformationDisclosureStatement = { tag: "InformationDisclosureStatement" };
+  export type PowerOfAttorney = { tag: "PowerOfAttorney" };
+  export type SequenceListing = { tag: "SequenceListing" };
 
   // Helper functions for constructing each variant of the tagged union.
   // ```
@@ -52,6 +57,11 @@ export namespace DocType {
   export const Claims = { tag: "Claims" };
   export const Drawings = { tag: "Drawings" };
   export const Abstract = { tag: "Abstract" };
+  export const InventorDeclaration = { tag: "InventorDeclaration" };
+  export const ApplicationDataSheet = { tag: "ApplicationDataSheet" };
+  export const InformationDisclosureStatement = { tag: "InformationDisclosureStatement" };
+  export const PowerOfAttorney = { tag: "PowerOfAttorney" };
+  export const SequenceListing = { tag: "SequenceListing" };
 
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createSumType([
@@ -59,6 +69,11 @@ export namespace DocType {
       new SumTypeVariant("Claims", AlgebraicType.createProductType([])),
       new SumTypeVariant("Drawings", AlgebraicType.createProductType([])),
       new SumTypeVariant("Abstract", AlgebraicType.createProductType([])),
+      new SumTypeVariant("InventorDeclaration", AlgebraicType.createProductType([])),
+      new SumTypeVariant("ApplicationDataSheet", AlgebraicType.createProductType([])),
+      new SumTypeVariant("InformationDisclosureStatement", AlgebraicType.createProductType([])),
+      new SumTypeVariant("PowerOfAttorney", AlgebraicType.createProductType([])),
+      new SumTypeVariant("SequenceListing", AlgebraicType.createProductType([])),
     ]);
   }
 
@@ -73,7 +88,7 @@ export namespace DocType {
 }
 
 // The tagged union or sum type for the algebraic type `DocType`.
-export type DocType = DocType.Spec | DocType.Claims | DocType.Drawings | DocType.Abstract;
+export type DocType = DocType.Spec | DocType.Claims | DocType.Drawings | DocType.Abstract | DocType.InventorDeclaration | DocType.ApplicationDataSheet | DocType.InformationDisclosureStatement | DocType.PowerOfAttorney | DocType.SequenceListing;
 
 export default DocType;
 
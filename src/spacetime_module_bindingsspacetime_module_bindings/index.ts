@@ -34,125 +34,474 @@ import {
 } from "@clockworklabs/spacetimedb-sdk";
 
 // Import and reexport all reducer arg types
-import { AddBlockchainRecord } from "./add_blockchain_record_reducer.ts";
-export { AddBlockchainRecord };
+import { AcknowledgeReminder } from "./acknowledge_reminder_reducer.ts";
+export { AcknowledgeReminder };
+import { AddAdmin } from "./add_admin_reducer.ts";
+export { AddAdmin };
+import { AddClassification } from "./add_classification_reducer.ts";
+export { AddClassification };
+import { AddCoOwner } from "./add_co_owner_reducer.ts";
+export { AddCoOwner };
+import { AddJurisdictionFiling } from "./add_jurisdiction_filing_reducer.ts";
+export { AddJurisdictionFiling };
+import { AddOrganizationMember } from "./add_organization_member_reducer.ts";
+export { AddOrganizationMember };
 import { AddPriorArtResult } from "./add_prior_art_result_reducer.ts";
 export { AddPriorArtResult };
 import { AddToPortfolio } from "./add_to_portfolio_reducer.ts";
 export { AddToPortfolio };
 import { AnalyticsTick } from "./analytics_tick_reducer.ts";
 export { AnalyticsTick };
+import { AnchorMerkleBatch } from "./anchor_merkle_batch_reducer.ts";
+export { AnchorMerkleBatch };
+import { AnchorNotarization } from "./anchor_notarization_reducer.ts";
+export { AnchorNotarization };
+import { AnnotatePriorArtCitation } from "./annotate_prior_art_citation_reducer.ts";
+export { AnnotatePriorArtCitation };
+import { BootstrapModule } from "./bootstrap_module_reducer.ts";
+export { BootstrapModule };
+import { CancelBatchAnchoring } from "./cancel_batch_anchoring_reducer.ts";
+export { CancelBatchAnchoring };
+import { ClaimNextJob } from "./claim_next_job_reducer.ts";
+export { ClaimNextJob };
 import { CloseCollabSession } from "./close_collab_session_reducer.ts";
 export { CloseCollabSession };
+import { CompactionTick } from "./compaction_tick_reducer.ts";
+export { CompactionTick };
+import { CompleteDeadline } from "./complete_deadline_reducer.ts";
+export { CompleteDeadline };
+import { CompleteJob } from "./complete_job_reducer.ts";
+export { CompleteJob };
+import { ComputeDocumentDiff } from "./compute_document_diff_reducer.ts";
+export { ComputeDocumentDiff };
+import { ConfirmClassification } from "./confirm_classification_reducer.ts";
+export { ConfirmClassification };
+import { ConfirmInventorship } from "./confirm_inventorship_reducer.ts";
+export { ConfirmInventorship };
 import { ConnectInventor } from "./connect_inventor_reducer.ts";
 export { ConnectInventor };
+import { CreateOrganization } from "./create_organization_reducer.ts";
+export { CreateOrganization };
+import { DisputeInventorship } from "./dispute_inventorship_reducer.ts";
+export { DisputeInventorship };
+import { DocketTick } from "./docket_tick_reducer.ts";
+export { DocketTick };
+import { EnqueueDocJob } from "./enqueue_doc_job_reducer.ts";
+export { EnqueueDocJob };
+import { ExportApplicationXml } from "./export_application_xml_reducer.ts";
+export { ExportApplicationXml };
+import { ExportPortfolio } from "./export_portfolio_reducer.ts";
+export { ExportPortfolio };
+import { FailJob } from "./fail_job_reducer.ts";
+export { FailJob };
+import { GenerateIds } from "./generate_ids_reducer.ts";
+export { GenerateIds };
+import { HeartbeatJob } from "./heartbeat_job_reducer.ts";
+export { HeartbeatJob };
 import { IdentityConnected } from "./identity_connected_reducer.ts";
 export { IdentityConnected };
 import { IdentityDisconnected } from "./identity_disconnected_reducer.ts";
 export { IdentityDisconnected };
+import { ImportApplicationXml } from "./import_application_xml_reducer.ts";
+export { ImportApplicationXml };
+import { ImportPortfolio } from "./import_portfolio_reducer.ts";
+export { ImportPortfolio };
 import { JoinCollabSession } from "./join_collab_session_reducer.ts";
 export { JoinCollabSession };
 import { LeaveCollabSession } from "./leave_collab_session_reducer.ts";
 export { LeaveCollabSession };
+import { LinkFamily } from "./link_family_reducer.ts";
+export { LinkFamily };
+import { MaintenanceTick } from "./maintenance_tick_reducer.ts";
+export { MaintenanceTick };
+import { MerkleBatchTick } from "./merkle_batch_tick_reducer.ts";
+export { MerkleBatchTick };
 import { MonitorTick } from "./monitor_tick_reducer.ts";
 export { MonitorTick };
+import { NotarizeApplication } from "./notarize_application_reducer.ts";
+export { NotarizeApplication };
+import { PauseJob } from "./pause_job_reducer.ts";
+export { PauseJob };
+import { PinFiledVersion } from "./pin_filed_version_reducer.ts";
+export { PinFiledVersion };
+import { PurchaseOfficeActionExtension } from "./purchase_office_action_extension_reducer.ts";
+export { PurchaseOfficeActionExtension };
+import { ReclaimDocJobLeases } from "./reclaim_doc_job_leases_reducer.ts";
+export { ReclaimDocJobLeases };
+import { RecordGrant } from "./record_grant_reducer.ts";
+export { RecordGrant };
+import { RecordMaintenancePayment } from "./record_maintenance_payment_reducer.ts";
+export { RecordMaintenancePayment };
+import { RecordOfficeAction } from "./record_office_action_reducer.ts";
+export { RecordOfficeAction };
+import { RecordOfficeActionResponse } from "./record_office_action_response_reducer.ts";
+export { RecordOfficeActionResponse };
+import { RegenerateDocument } from "./regenerate_document_reducer.ts";
+export { RegenerateDocument };
+import { RegisterDocWorker } from "./register_doc_worker_reducer.ts";
+export { RegisterDocWorker };
 import { RegisterInventor } from "./register_inventor_reducer.ts";
 export { RegisterInventor };
+import { RemoveAdmin } from "./remove_admin_reducer.ts";
+export { RemoveAdmin };
+import { RemoveApplicationInventor } from "./remove_application_inventor_reducer.ts";
+export { RemoveApplicationInventor };
+import { RemoveClassification } from "./remove_classification_reducer.ts";
+export { RemoveClassification };
+import { RemoveCoOwner } from "./remove_co_owner_reducer.ts";
+export { RemoveCoOwner };
+import { RemoveDocWorker } from "./remove_doc_worker_reducer.ts";
+export { RemoveDocWorker };
+import { RemoveOrganizationMember } from "./remove_organization_member_reducer.ts";
+export { RemoveOrganizationMember };
+import { RequestBatchAnchoring } from "./request_batch_anchoring_reducer.ts";
+export { RequestBatchAnchoring };
 import { ResolveAlert } from "./resolve_alert_reducer.ts";
 export { ResolveAlert };
 import { RespondConnection } from "./respond_connection_reducer.ts";
 export { RespondConnection };
+import { ResumeJob } from "./resume_job_reducer.ts";
+export { ResumeJob };
+import { ReviewPriorArt } from "./review_prior_art_reducer.ts";
+export { ReviewPriorArt };
+import { SaveDocumentVersion } from "./save_document_version_reducer.ts";
+export { SaveDocumentVersion };
+import { SetApplicationInventor } from "./set_application_inventor_reducer.ts";
+export { SetApplicationInventor };
+import { SetFilingDetails } from "./set_filing_details_reducer.ts";
+export { SetFilingDetails };
+import { SetJobInterval } from "./set_job_interval_reducer.ts";
+export { SetJobInterval };
+import { SetReminderLeadDays } from "./set_reminder_lead_days_reducer.ts";
+export { SetReminderLeadDays };
 import { StartCollabSession } from "./start_collab_session_reducer.ts";
 export { StartCollabSession };
 import { SubmitPatent } from "./submit_patent_reducer.ts";
 export { SubmitPatent };
+import { SuggestClassifications } from "./suggest_classifications_reducer.ts";
+export { SuggestClassifications };
+import { UnlinkFamily } from "./unlink_family_reducer.ts";
+export { UnlinkFamily };
+import { UpdateJurisdictionStatus } from "./update_jurisdiction_status_reducer.ts";
+export { UpdateJurisdictionStatus };
 import { UpdatePatentStatus } from "./update_patent_status_reducer.ts";
 export { UpdatePatentStatus };
 import { UpdateStageProgress } from "./update_stage_progress_reducer.ts";
 export { UpdateStageProgress };
 import { UpsertDocumentGeneration } from "./upsert_document_generation_reducer.ts";
 export { UpsertDocumentGeneration };
+import { VerifyMerkleInclusion } from "./verify_merkle_inclusion_reducer.ts";
+export { VerifyMerkleInclusion };
+import { VerifyMerkleProof } from "./verify_merkle_proof_reducer.ts";
+export { VerifyMerkleProof };
 
 // Import and reexport all table handle types
+import { AdminTableHandle } from "./admin_table.ts";
+export { AdminTableHandle };
 import { AnalyticsScheduleTableHandle } from "./analytics_schedule_table.ts";
 export { AnalyticsScheduleTableHandle };
+import { ApplicationEventTableHandle } from "./application_event_table.ts";
+export { ApplicationEventTableHandle };
+import { ApplicationInventorTableHandle } from "./application_inventor_table.ts";
+export { ApplicationInventorTableHandle };
 import { BlockchainRecordTableHandle } from "./blockchain_record_table.ts";
 export { BlockchainRecordTableHandle };
+import { BlockchainRecordBatchTableHandle } from "./blockchain_record_batch_table.ts";
+export { BlockchainRecordBatchTableHandle };
+import { ClassificationTableHandle } from "./classification_table.ts";
+export { ClassificationTableHandle };
+import { CoOwnerGrantTableHandle } from "./co_owner_grant_table.ts";
+export { CoOwnerGrantTableHandle };
 import { CollabParticipantTableHandle } from "./collab_participant_table.ts";
 export { CollabParticipantTableHandle };
 import { CollabSessionTableHandle } from "./collab_session_table.ts";
 export { CollabSessionTableHandle };
+import { CompactionScheduleTableHandle } from "./compaction_schedule_table.ts";
+export { CompactionScheduleTableHandle };
+import { DeadlineTableHandle } from "./deadline_table.ts";
+export { DeadlineTableHandle };
+import { DeadlineReminderTableHandle } from "./deadline_reminder_table.ts";
+export { DeadlineReminderTableHandle };
+import { DocGenAttemptTableHandle } from "./doc_gen_attempt_table.ts";
+export { DocGenAttemptTableHandle };
+import { DocGenMetricTableHandle } from "./doc_gen_metric_table.ts";
+export { DocGenMetricTableHandle };
+import { DocJobTableHandle } from "./doc_job_table.ts";
+export { DocJobTableHandle };
+import { DocLeaseScheduleTableHandle } from "./doc_lease_schedule_table.ts";
+export { DocLeaseScheduleTableHandle };
+import { DocWorkerTableHandle } from "./doc_worker_table.ts";
+export { DocWorkerTableHandle };
+import { DocketScheduleTableHandle } from "./docket_schedule_table.ts";
+export { DocketScheduleTableHandle };
+import { DocumentDiffTableHandle } from "./document_diff_table.ts";
+export { DocumentDiffTableHandle };
 import { DocumentGenerationTableHandle } from "./document_generation_table.ts";
 export { DocumentGenerationTableHandle };
+import { DocumentVersionTableHandle } from "./document_version_table.ts";
+export { DocumentVersionTableHandle };
+import { ExportArtifactTableHandle } from "./export_artifact_table.ts";
+export { ExportArtifactTableHandle };
+import { FamilyLinkTableHandle } from "./family_link_table.ts";
+export { FamilyLinkTableHandle };
+import { FamilyNodeTableHandle } from "./family_node_table.ts";
+export { FamilyNodeTableHandle };
+import { FilingDetailTableHandle } from "./filing_detail_table.ts";
+export { FilingDetailTableHandle };
+import { FilingReadinessTableHandle } from "./filing_readiness_table.ts";
+export { FilingReadinessTableHandle };
+import { GrantRecordTableHandle } from "./grant_record_table.ts";
+export { GrantRecordTableHandle };
+import { IdsFilingTableHandle } from "./ids_filing_table.ts";
+export { IdsFilingTableHandle };
+import { ImportReportTableHandle } from "./import_report_table.ts";
+export { ImportReportTableHandle };
 import { InfringementAlertTableHandle } from "./infringement_alert_table.ts";
 export { InfringementAlertTableHandle };
 import { InventorTableHandle } from "./inventor_table.ts";
 export { InventorTableHandle };
 import { InventorConnectionTableHandle } from "./inventor_connection_table.ts";
 export { InventorConnectionTableHandle };
+import { JurisdictionFilingTableHandle } from "./jurisdiction_filing_table.ts";
+export { JurisdictionFilingTableHandle };
+import { MaintenanceFeeTableHandle } from "./maintenance_fee_table.ts";
+export { MaintenanceFeeTableHandle };
+import { MaintenanceScheduleTableHandle } from "./maintenance_schedule_table.ts";
+export { MaintenanceScheduleTableHandle };
+import { MarketTrendRollupTableHandle } from "./market_trend_rollup_table.ts";
+export { MarketTrendRollupTableHandle };
 import { MarketTrendSnapshotTableHandle } from "./market_trend_snapshot_table.ts";
 export { MarketTrendSnapshotTableHandle };
+import { MerkleBatchTableHandle } from "./merkle_batch_table.ts";
+export { MerkleBatchTableHandle };
+import { MerkleScheduleTableHandle } from "./merkle_schedule_table.ts";
+export { MerkleScheduleTableHandle };
+import { ModuleConfigTableHandle } from "./module_config_table.ts";
+export { ModuleConfigTableHandle };
 import { MonitoringScheduleTableHandle } from "./monitoring_schedule_table.ts";
 export { MonitoringScheduleTableHandle };
+import { NotarizationTableHandle } from "./notarization_table.ts";
+export { NotarizationTableHandle };
+import { OfficeActionTableHandle } from "./office_action_table.ts";
+export { OfficeActionTableHandle };
+import { OrganizationTableHandle } from "./organization_table.ts";
+export { OrganizationTableHandle };
+import { OrganizationMemberTableHandle } from "./organization_member_table.ts";
+export { OrganizationMemberTableHandle };
 import { PatentApplicationTableHandle } from "./patent_application_table.ts";
 export { PatentApplicationTableHandle };
+import { PipelineMetricTableHandle } from "./pipeline_metric_table.ts";
+export { PipelineMetricTableHandle };
 import { PortfolioEntryTableHandle } from "./portfolio_entry_table.ts";
 export { PortfolioEntryTableHandle };
+import { PortfolioTrendSnapshotTableHandle } from "./portfolio_trend_snapshot_table.ts";
+export { PortfolioTrendSnapshotTableHandle };
+import { PriorArtCitationTableHandle } from "./prior_art_citation_table.ts";
+export { PriorArtCitationTableHandle };
 import { PriorArtResultTableHandle } from "./prior_art_result_table.ts";
 export { PriorArtResultTableHandle };
+import { PriorArtReviewTableHandle } from "./prior_art_review_table.ts";
+export { PriorArtReviewTableHandle };
+import { ReminderLeadTimeTableHandle } from "./reminder_lead_time_table.ts";
+export { ReminderLeadTimeTableHandle };
 import { StageProgressTableHandle } from "./stage_progress_table.ts";
 export { StageProgressTableHandle };
+import { StageTransitionTableHandle } from "./stage_transition_table.ts";
+export { StageTransitionTableHandle };
 
 // Import and reexport all types
+import { Admin } from "./admin_type.ts";
+export { Admin };
+import { AlertEvent } from "./alert_event_type.ts";
+export { AlertEvent };
 import { AlertSeverity } from "./alert_severity_type.ts";
 export { AlertSeverity };
 import { AnalyticsSchedule } from "./analytics_schedule_type.ts";
 export { AnalyticsSchedule };
+import { ApplicationEvent } from "./application_event_type.ts";
+export { ApplicationEvent };
+import { ApplicationEventPayload } from "./application_event_payload_type.ts";
+export { ApplicationEventPayload };
+import { ApplicationInventor } from "./application_inventor_type.ts";
+export { ApplicationInventor };
 import { BlockchainRecord } from "./blockchain_record_type.ts";
 export { BlockchainRecord };
+import { BlockchainRecordBatch } from "./blockchain_record_batch_type.ts";
+export { BlockchainRecordBatch };
+import { Classification } from "./classification_type.ts";
+export { Classification };
+import { ClassificationScheme } from "./classification_scheme_type.ts";
+export { ClassificationScheme };
+import { ClassificationSource } from "./classification_source_type.ts";
+export { ClassificationSource };
+import { CoOwnerGrant } from "./co_owner_grant_type.ts";
+export { CoOwnerGrant };
 import { CollabStatus } from "./collab_status_type.ts";
 export { CollabStatus };
 import { CollaborationSession } from "./collaboration_session_type.ts";
 export { CollaborationSession };
+import { CompactionSchedule } from "./compaction_schedule_type.ts";
+export { CompactionSchedule };
 import { ConnectionStatus } from "./connection_status_type.ts";
 export { ConnectionStatus };
+import { Deadline } from "./deadline_type.ts";
+export { Deadline };
+import { DeadlineKind } from "./deadline_kind_type.ts";
+export { DeadlineKind };
+import { DeadlineReminder } from "./deadline_reminder_type.ts";
+export { DeadlineReminder };
+import { DeclarationStatus } from "./declaration_status_type.ts";
+export { DeclarationStatus };
+import { DiffOp } from "./diff_op_type.ts";
+export { DiffOp };
+import { DiffSegment } from "./diff_segment_type.ts";
+export { DiffSegment };
+import { DocGenAttempt } from "./doc_gen_attempt_type.ts";
+export { DocGenAttempt };
+import { DocGenEvent } from "./doc_gen_event_type.ts";
+export { DocGenEvent };
+import { DocGenMetric } from "./doc_gen_metric_type.ts";
+export { DocGenMetric };
 import { DocGenStatus } from "./doc_gen_status_type.ts";
 export { DocGenStatus };
+import { DocJob } from "./doc_job_type.ts";
+export { DocJob };
+import { DocJobStatus } from "./doc_job_status_type.ts";
+export { DocJobStatus };
+import { DocLeaseSchedule } from "./doc_lease_schedule_type.ts";
+export { DocLeaseSchedule };
 import { DocType } from "./doc_type_type.ts";
 export { DocType };
+import { DocWorker } from "./doc_worker_type.ts";
+export { DocWorker };
+import { DocketSchedule } from "./docket_schedule_type.ts";
+export { DocketSchedule };
+import { DocumentDiff } from "./document_diff_type.ts";
+export { DocumentDiff };
 import { DocumentGeneration } from "./document_generation_type.ts";
 export { DocumentGeneration };
+import { DocumentVersion } from "./document_version_type.ts";
+export { DocumentVersion };
+import { EntitySize } from "./entity_size_type.ts";
+export { EntitySize };
+import { ExportArtifact } from "./export_artifact_type.ts";
+export { ExportArtifact };
+import { ExportFormat } from "./export_format_type.ts";
+export { ExportFormat };
+import { FamilyLink } from "./family_link_type.ts";
+export { FamilyLink };
+import { FamilyNode } from "./family_node_type.ts";
+export { FamilyNode };
+import { FamilyRelationship } from "./family_relationship_type.ts";
+export { FamilyRelationship };
+import { FilingDetail } from "./filing_detail_type.ts";
+export { FilingDetail };
+import { FilingReadiness } from "./filing_readiness_type.ts";
+export { FilingReadiness };
+import { FilingType } from "./filing_type_type.ts";
+export { FilingType };
+import { GrantRecord } from "./grant_record_type.ts";
+export { GrantRecord };
+import { IdsFiling } from "./ids_filing_type.ts";
+export { IdsFiling };
+import { ImportFormat } from "./import_format_type.ts";
+export { ImportFormat };
+import { ImportReport } from "./import_report_type.ts";
+export { ImportReport };
+import { ImportRowError } from "./import_row_error_type.ts";
+export { ImportRowError };
 import { InfringementAlert } from "./infringement_alert_type.ts";
 export { InfringementAlert };
 import { InventorConnection } from "./inventor_connection_type.ts";
 export { InventorConnection };
 import { InventorProfile } from "./inventor_profile_type.ts";
 export { InventorProfile };
+import { JurisdictionFiling } from "./jurisdiction_filing_type.ts";
+export { JurisdictionFiling };
+import { MaintenanceFee } from "./maintenance_fee_type.ts";
+export { MaintenanceFee };
+import { MaintenanceSchedule } from "./maintenance_schedule_type.ts";
+export { MaintenanceSchedule };
+import { MarketTrendRollup } from "./market_trend_rollup_type.ts";
+export { MarketTrendRollup };
 import { MarketTrendSnapshot } from "./market_trend_snapshot_type.ts";
 export { MarketTrendSnapshot };
+import { MerkleBatch } from "./merkle_batch_type.ts";
+export { MerkleBatch };
+import { MerkleLeaf } from "./merkle_leaf_type.ts";
+export { MerkleLeaf };
+import { MerkleProofStep } from "./merkle_proof_step_type.ts";
+export { MerkleProofStep };
+import { MerkleSchedule } from "./merkle_schedule_type.ts";
+export { MerkleSchedule };
+import { ModuleConfig } from "./module_config_type.ts";
+export { ModuleConfig };
 import { MonitoringSchedule } from "./monitoring_schedule_type.ts";
 export { MonitoringSchedule };
+import { Notarization } from "./notarization_type.ts";
+export { Notarization };
+import { OfficeAction } from "./office_action_type.ts";
+export { OfficeAction };
+import { OfficeActionType } from "./office_action_type_type.ts";
+export { OfficeActionType };
+import { Organization } from "./organization_type.ts";
+export { Organization };
+import { OrganizationMember } from "./organization_member_type.ts";
+export { OrganizationMember };
 import { PatentApplication } from "./patent_application_type.ts";
 export { PatentApplication };
+import { PatentOffice } from "./patent_office_type.ts";
+export { PatentOffice };
 import { PatentPortfolioEntry } from "./patent_portfolio_entry_type.ts";
 export { PatentPortfolioEntry };
 import { PatentStatus } from "./patent_status_type.ts";
 export { PatentStatus };
+import { PipelineMetric } from "./pipeline_metric_type.ts";
+export { PipelineMetric };
+import { PipelineMetricKind } from "./pipeline_metric_kind_type.ts";
+export { PipelineMetricKind };
 import { PortfolioRole } from "./portfolio_role_type.ts";
 export { PortfolioRole };
+import { PortfolioTrendSnapshot } from "./portfolio_trend_snapshot_type.ts";
+export { PortfolioTrendSnapshot };
+import { PriorArtCitation } from "./prior_art_citation_type.ts";
+export { PriorArtCitation };
 import { PriorArtResult } from "./prior_art_result_type.ts";
 export { PriorArtResult };
+import { PriorArtReview } from "./prior_art_review_type.ts";
+export { PriorArtReview };
+import { ReferenceKind } from "./reference_kind_type.ts";
+export { ReferenceKind };
+import { ReminderLeadTime } from "./reminder_lead_time_type.ts";
+export { ReminderLeadTime };
+import { RollupResolution } from "./rollup_resolution_type.ts";
+export { RollupResolution };
 import { SessionParticipant } from "./session_participant_type.ts";
 export { SessionParticipant };
 import { Stage } from "./stage_type.ts";
 export { Stage };
 import { StageProgress } from "./stage_progress_type.ts";
 export { StageProgress };
+import { StageTransition } from "./stage_transition_type.ts";
+export { StageTransition };
+import { StageUpdate } from "./stage_update_type.ts";
+export { StageUpdate };
+import { StatusChange } from "./status_change_type.ts";
+export { StatusChange };
 import { TrendMetric } from "./trend_metric_type.ts";
 export { TrendMetric };
 
 const REMOTE_MODULE = {
   tables: {
+    admin: {
+      tableName: "admin",
+      rowType: Admin.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: Admin.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     analytics_schedule: {
       tableName: "analytics_schedule",
       rowType: AnalyticsSchedule.getTypeScriptAlgebraicType(),
@@ -162,6 +511,24 @@ const REMOTE_MODULE = {
         colType: AnalyticsSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    application_event: {
+      tableName: "application_event",
+      rowType: ApplicationEvent.getTypeScriptAlgebraicType(),
+      primaryKey: "eventId",
+      primaryKeyInfo: {
+        colName: "eventId",
+        colType: ApplicationEvent.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    application_inventor: {
+      tableName: "application_inventor",
+      rowType: ApplicationInventor.getTypeScriptAlgebraicType(),
+      primaryKey: "rowId",
+      primaryKeyInfo: {
+        colName: "rowId",
+        colType: ApplicationInventor.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     blockchain_record: {
       tableName: "blockchain_record",
       rowType: BlockchainRecord.getTypeScriptAlgebraicType(),
@@ -171,6 +538,33 @@ const REMOTE_MODULE = {
         colType: BlockchainRecord.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    blockchain_record_batch: {
+      tableName: "blockchain_record_batch",
+      rowType: BlockchainRecordBatch.getTypeScriptAlgebraicType(),
+      primaryKey: "recordId",
+      primaryKeyInfo: {
+        colName: "recordId",
+        colType: BlockchainRecordBatch.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    classification: {
+      tableName: "classification",
+      rowType: Classification.getTypeScriptAlgebraicType(),
+      primaryKey: "classificationId",
+      primaryKeyInfo: {
+        colName: "classificationId",
+        colType: Classification.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    co_owner_grant: {
+      tableName: "co_owner_grant",
+      rowType: CoOwnerGrant.getTypeScriptAlgebraicType(),
+      primaryKey: "rowId",
+      primaryKeyInfo: {
+        colName: "rowId",
+        colType: CoOwnerGrant.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     collab_participant: {
       tableName: "collab_participant",
       rowType: SessionParticipant.getTypeScriptAlgebraicType(),
@@ -189,6 +583,96 @@ const REMOTE_MODULE = {
         colType: CollaborationSession.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    compaction_schedule: {
+      tableName: "compaction_schedule",
+      rowType: CompactionSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: CompactionSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    deadline: {
+      tableName: "deadline",
+      rowType: Deadline.getTypeScriptAlgebraicType(),
+      primaryKey: "deadlineId",
+      primaryKeyInfo: {
+        colName: "deadlineId",
+        colType: Deadline.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    deadline_reminder: {
+      tableName: "deadline_reminder",
+      rowType: DeadlineReminder.getTypeScriptAlgebraicType(),
+      primaryKey: "reminderId",
+      primaryKeyInfo: {
+        colName: "reminderId",
+        colType: DeadlineReminder.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    doc_gen_attempt: {
+      tableName: "doc_gen_attempt",
+      rowType: DocGenAttempt.getTypeScriptAlgebraicType(),
+      primaryKey: "attemptId",
+      primaryKeyInfo: {
+        colName: "attemptId",
+        colType: DocGenAttempt.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    doc_gen_metric: {
+      tableName: "doc_gen_metric",
+      rowType: DocGenMetric.getTypeScriptAlgebraicType(),
+      primaryKey: "metricId",
+      primaryKeyInfo: {
+        colName: "metricId",
+        colType: DocGenMetric.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    doc_job: {
+      tableName: "doc_job",
+      rowType: DocJob.getTypeScriptAlgebraicType(),
+      primaryKey: "jobId",
+      primaryKeyInfo: {
+        colName: "jobId",
+        colType: DocJob.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    doc_lease_schedule: {
+      tableName: "doc_lease_schedule",
+      rowType: DocLeaseSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: DocLeaseSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    doc_worker: {
+      tableName: "doc_worker",
+      rowType: DocWorker.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: DocWorker.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    docket_schedule: {
+      tableName: "docket_schedule",
+      rowType: DocketSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: DocketSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    document_diff: {
+      tableName: "document_diff",
+      rowType: DocumentDiff.getTypeScriptAlgebraicType(),
+      primaryKey: "diffId",
+      primaryKeyInfo: {
+        colName: "diffId",
+        colType: DocumentDiff.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     document_generation: {
       tableName: "document_generation",
       rowType: DocumentGeneration.getTypeScriptAlgebraicType(),
@@ -198,6 +682,87 @@ const REMOTE_MODULE = {
         colType: DocumentGeneration.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    document_version: {
+      tableName: "document_version",
+      rowType: DocumentVersion.getTypeScriptAlgebraicType(),
+      primaryKey: "versionId",
+      primaryKeyInfo: {
+        colName: "versionId",
+        colType: DocumentVersion.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    export_artifact: {
+      tableName: "export_artifact",
+      rowType: ExportArtifact.getTypeScriptAlgebraicType(),
+      primaryKey: "artifactId",
+      primaryKeyInfo: {
+        colName: "artifactId",
+        colType: ExportArtifact.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    family_link: {
+      tableName: "family_link",
+      rowType: FamilyLink.getTypeScriptAlgebraicType(),
+      primaryKey: "linkId",
+      primaryKeyInfo: {
+        colName: "linkId",
+        colType: FamilyLink.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    family_node: {
+      tableName: "family_node",
+      rowType: FamilyNode.getTypeScriptAlgebraicType(),
+      primaryKey: "applicationId",
+      primaryKeyInfo: {
+        colName: "applicationId",
+        colType: FamilyNode.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    filing_detail: {
+      tableName: "filing_detail",
+      rowType: FilingDetail.getTypeScriptAlgebraicType(),
+      primaryKey: "applicationId",
+      primaryKeyInfo: {
+        colName: "applicationId",
+        colType: FilingDetail.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    filing_readiness: {
+      tableName: "filing_readiness",
+      rowType: FilingReadiness.getTypeScriptAlgebraicType(),
+      primaryKey: "rowId",
+      primaryKeyInfo: {
+        colName: "rowId",
+        colType: FilingReadiness.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    grant_record: {
+      tableName: "grant_record",
+      rowType: GrantRecord.getTypeScriptAlgebraicType(),
+      primaryKey: "applicationId",
+      primaryKeyInfo: {
+        colName: "applicationId",
+        colType: GrantRecord.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    ids_filing: {
+      tableName: "ids_filing",
+      rowType: IdsFiling.getTypeScriptAlgebraicType(),
+      primaryKey: "idsId",
+      primaryKeyInfo: {
+        colName: "idsId",
+        colType: IdsFiling.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    import_report: {
+      tableName: "import_report",
+      rowType: ImportReport.getTypeScriptAlgebraicType(),
+      primaryKey: "reportId",
+      primaryKeyInfo: {
+        colName: "reportId",
+        colType: ImportReport.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     infringement_alert: {
       tableName: "infringement_alert",
       rowType: InfringementAlert.getTypeScriptAlgebraicType(),
@@ -225,6 +790,42 @@ const REMOTE_MODULE = {
         colType: InventorConnection.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    jurisdiction_filing: {
+      tableName: "jurisdiction_filing",
+      rowType: JurisdictionFiling.getTypeScriptAlgebraicType(),
+      primaryKey: "filingId",
+      primaryKeyInfo: {
+        colName: "filingId",
+        colType: JurisdictionFiling.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    maintenance_fee: {
+      tableName: "maintenance_fee",
+      rowType: MaintenanceFee.getTypeScriptAlgebraicType(),
+      primaryKey: "feeId",
+      primaryKeyInfo: {
+        colName: "feeId",
+        colType: MaintenanceFee.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    maintenance_schedule: {
+      tableName: "maintenance_schedule",
+      rowType: MaintenanceSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: MaintenanceSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    market_trend_rollup: {
+      tableName: "market_trend_rollup",
+      rowType: MarketTrendRollup.getTypeScriptAlgebraicType(),
+      primaryKey: "rollupId",
+      primaryKeyInfo: {
+        colName: "rollupId",
+        colType: MarketTrendRollup.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     market_trend_snapshot: {
       tableName: "market_trend_snapshot",
       rowType: MarketTrendSnapshot.getTypeScriptAlgebraicType(),
@@ -234,6 +835,33 @@ const REMOTE_MODULE = {
         colType: MarketTrendSnapshot.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    merkle_batch: {
+      tableName: "merkle_batch",
+      rowType: MerkleBatch.getTypeScriptAlgebraicType(),
+      primaryKey: "batchId",
+      primaryKeyInfo: {
+        colName: "batchId",
+        colType: MerkleBatch.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    merkle_schedule: {
+      tableName: "merkle_schedule",
+      rowType: MerkleSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: MerkleSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    module_config: {
+      tableName: "module_config",
+      rowType: ModuleConfig.getTypeScriptAlgebraicType(),
+      primaryKey: "job",
+      primaryKeyInfo: {
+        colName: "job",
+        colType: ModuleConfig.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     monitoring_schedule: {
       tableName: "monitoring_schedule",
       rowType: MonitoringSchedule.getTypeScriptAlgebraicType(),
@@ -243,6 +871,42 @@ const REMOTE_MODULE = {
         colType: MonitoringSchedule.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    notarization: {
+      tableName: "notarization",
+      rowType: Notarization.getTypeScriptAlgebraicType(),
+      primaryKey: "notarizationId",
+      primaryKeyInfo: {
+        colName: "notarizationId",
+        colType: Notarization.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    office_action: {
+      tableName: "office_action",
+      rowType: OfficeAction.getTypeScriptAlgebraicType(),
+      primaryKey: "actionId",
+      primaryKeyInfo: {
+        colName: "actionId",
+        colType: OfficeAction.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    organization: {
+      tableName: "organization",
+      rowType: Organization.getTypeScriptAlgebraicType(),
+      primaryKey: "name",
+      primaryKeyInfo: {
+        colName: "name",
+        colType: Organization.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    organization_member: {
+      tableName: "organization_member",
+      rowType: OrganizationMember.getTypeScriptAlgebraicType(),
+      primaryKey: "rowId",
+      primaryKeyInfo: {
+        colName: "rowId",
+        colType: OrganizationMember.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     patent_application: {
       tableName: "patent_application",
       rowType: PatentApplication.getTypeScriptAlgebraicType(),
@@ -252,6 +916,15 @@ const REMOTE_MODULE = {
         colType: PatentApplication.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    pipeline_metric: {
+      tableName: "pipeline_metric",
+      rowType: PipelineMetric.getTypeScriptAlgebraicType(),
+      primaryKey: "metricId",
+      primaryKeyInfo: {
+        colName: "metricId",
+        colType: PipelineMetric.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     portfolio_entry: {
       tableName: "portfolio_entry",
       rowType: PatentPortfolioEntry.getTypeScriptAlgebraicType(),
@@ -261,6 +934,24 @@ const REMOTE_MODULE = {
         colType: PatentPortfolioEntry.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    portfolio_trend_snapshot: {
+      tableName: "portfolio_trend_snapshot",
+      rowType: PortfolioTrendSnapshot.getTypeScriptAlgebraicType(),
+      primaryKey: "snapshotId",
+      primaryKeyInfo: {
+        colName: "snapshotId",
+        colType: PortfolioTrendSnapshot.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    prior_art_citation: {
+      tableName: "prior_art_citation",
+      rowType: PriorArtCitation.getTypeScriptAlgebraicType(),
+      primaryKey: "resultId",
+      primaryKeyInfo: {
+        colName: "resultId",
+        colType: PriorArtCitation.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     prior_art_result: {
       tableName: "prior_art_result",
       rowType: PriorArtResult.getTypeScriptAlgebraicType(),
@@ -270,6 +961,24 @@ const REMOTE_MODULE = {
         colType: PriorArtResult.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    prior_art_review: {
+      tableName: "prior_art_review",
+      rowType: PriorArtReview.getTypeScriptAlgebraicType(),
+      primaryKey: "resultId",
+      primaryKeyInfo: {
+        colName: "resultId",
+        colType: PriorArtReview.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
+    reminder_lead_time: {
+      tableName: "reminder_lead_time",
+      rowType: ReminderLeadTime.getTypeScriptAlgebraicType(),
+      primaryKey: "leadDays",
+      primaryKeyInfo: {
+        colName: "leadDays",
+        colType: ReminderLeadTime.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
     stage_progress: {
       tableName: "stage_progress",
       rowType: StageProgress.getTypeScriptAlgebraicType(),
@@ -279,11 +988,40 @@ const REMOTE_MODULE = {
         colType: StageProgress.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
       },
     },
+    stage_transition: {
+      tableName: "stage_transition",
+      rowType: StageTransition.getTypeScriptAlgebraicType(),
+      primaryKey: "transitionId",
+      primaryKeyInfo: {
+        colName: "transitionId",
+        colType: StageTransition.getTypeScriptAlgebraicType().product.elements[0].algebraicType,
+      },
+    },
   },
   reducers: {
-    add_blockchain_record: {
-      reducerName: "add_blockchain_record",
-      argsType: AddBlockchainRecord.getTypeScriptAlgebraicType(),
+    acknowledge_reminder: {
+      reducerName: "acknowledge_reminder",
+      argsType: AcknowledgeReminder.getTypeScriptAlgebraicType(),
+    },
+    add_admin: {
+      reducerName: "add_admin",
+      argsType: AddAdmin.getTypeScriptAlgebraicType(),
+    },
+    add_classification: {
+      reducerName: "add_classification",
+      argsType: AddClassification.getTypeScriptAlgebraicType(),
+    },
+    add_co_owner: {
+      reducerName: "add_co_owner",
+      argsType: AddCoOwner.getTypeScriptAlgebraicType(),
+    },
+    add_jurisdiction_filing: {
+      reducerName: "add_jurisdiction_filing",
+      argsType: AddJurisdictionFiling.getTypeScriptAlgebraicType(),
+    },
+    add_organization_member: {
+      reducerName: "add_organization_member",
+      argsType: AddOrganizationMember.getTypeScriptAlgebraicType(),
     },
     add_prior_art_result: {
       reducerName: "add_prior_art_result",
@@ -297,14 +1035,98 @@ const REMOTE_MODULE = {
       reducerName: "analytics_tick",
       argsType: AnalyticsTick.getTypeScriptAlgebraicType(),
     },
+    anchor_merkle_batch: {
+      reducerName: "anchor_merkle_batch",
+      argsType: AnchorMerkleBatch.getTypeScriptAlgebraicType(),
+    },
+    anchor_notarization: {
+      reducerName: "anchor_notarization",
+      argsType: AnchorNotarization.getTypeScriptAlgebraicType(),
+    },
+    annotate_prior_art_citation: {
+      reducerName: "annotate_prior_art_citation",
+      argsType: AnnotatePriorArtCitation.getTypeScriptAlgebraicType(),
+    },
+    bootstrap_module: {
+      reducerName: "bootstrap_module",
+      argsType: BootstrapModule.getTypeScriptAlgebraicType(),
+    },
+    cancel_batch_anchoring: {
+      reducerName: "cancel_batch_anchoring",
+      argsType: CancelBatchAnchoring.getTypeScriptAlgebraicType(),
+    },
+    claim_next_job: {
+      reducerName: "claim_next_job",
+      argsType: ClaimNextJob.getTypeScriptAlgebraicType(),
+    },
     close_collab_session: {
       reducerName: "close_collab_session",
       argsType: CloseCollabSession.getTypeScriptAlgebraicType(),
     },
+    compaction_tick: {
+      reducerName: "compaction_tick",
+      argsType: CompactionTick.getTypeScriptAlgebraicType(),
+    },
+    complete_deadline: {
+      reducerName: "complete_deadline",
+      argsType: CompleteDeadline.getTypeScriptAlgebraicType(),
+    },
+    complete_job: {
+      reducerName: "complete_job",
+      argsType: CompleteJob.getTypeScriptAlgebraicType(),
+    },
+    compute_document_diff: {
+      reducerName: "compute_document_diff",
+      argsType: ComputeDocumentDiff.getTypeScriptAlgebraicType(),
+    },
+    confirm_classification: {
+      reducerName: "confirm_classification",
+      argsType: ConfirmClassification.getTypeScriptAlgebraicType(),
+    },
+    confirm_inventorship: {
+      reducerName: "confirm_inventorship",
+      argsType: ConfirmInventorship.getTypeScriptAlgebraicType(),
+    },
     connect_inventor: {
       reducerName: "connect_inventor",
       argsType: ConnectInventor.getTypeScriptAlgebraicType(),
     },
+    create_organization: {
+      reducerName: "create_organization",
+      argsType: CreateOrganization.getTypeScriptAlgebraicType(),
+    },
+    dispute_inventorship: {
+      reducerName: "dispute_inventorship",
+      argsType: DisputeInventorship.getTypeScriptAlgebraicType(),
+    },
+    docket_tick: {
+      reducerName: "docket_tick",
+      argsType: DocketTick.getTypeScriptAlgebraicType(),
+    },
+    enqueue_doc_job: {
+      reducerName: "enqueue_doc_job",
+      argsType: EnqueueDocJob.getTypeScriptAlgebraicType(),
+    },
+    export_application_xml: {
+      reducerName: "export_application_xml",
+      argsType: ExportApplicationXml.getTypeScriptAlgebraicType(),
+    },
+    export_portfolio: {
+      reducerName: "export_portfolio",
+      argsType: ExportPortfolio.getTypeScriptAlgebraicType(),
+    },
+    fail_job: {
+      reducerName: "fail_job",
+      argsType: FailJob.getTypeScriptAlgebraicType(),
+    },
+    generate_ids: {
+      reducerName: "generate_ids",
+      argsType: GenerateIds.getTypeScriptAlgebraicType(),
+    },
+    heartbeat_job: {
+      reducerName: "heartbeat_job",
+      argsType: HeartbeatJob.getTypeScriptAlgebraicType(),
+    },
     identity_connected: {
       reducerName: "identity_connected",
       argsType: IdentityConnected.getTypeScriptAlgebraicType(),
@@ -313,6 +1135,14 @@ const REMOTE_MODULE = {
       reducerName: "identity_disconnected",
       argsType: IdentityDisconnected.getTypeScriptAlgebraicType(),
     },
+    import_application_xml: {
+      reducerName: "import_application_xml",
+      argsType: ImportApplicationXml.getTypeScriptAlgebraicType(),
+    },
+    import_portfolio: {
+      reducerName: "import_portfolio",
+      argsType: ImportPortfolio.getTypeScriptAlgebraicType(),
+    },
     join_collab_session: {
       reducerName: "join_collab_session",
       argsType: JoinCollabSession.getTypeScriptAlgebraicType(),
@@ -321,14 +1151,98 @@ const REMOTE_MODULE = {
       reducerName: "leave_collab_session",
       argsType: LeaveCollabSession.getTypeScriptAlgebraicType(),
     },
+    link_family: {
+      reducerName: "link_family",
+      argsType: LinkFamily.getTypeScriptAlgebraicType(),
+    },
+    maintenance_tick: {
+      reducerName: "maintenance_tick",
+      argsType: MaintenanceTick.getTypeScriptAlgebraicType(),
+    },
+    merkle_batch_tick: {
+      reducerName: "merkle_batch_tick",
+      argsType: MerkleBatchTick.getTypeScriptAlgebraicType(),
+    },
     monitor_tick: {
       reducerName: "monitor_tick",
       argsType: MonitorTick.getTypeScriptAlgebraicType(),
     },
+    notarize_application: {
+      reducerName: "notarize_application",
+      argsType: NotarizeApplication.getTypeScriptAlgebraicType(),
+    },
+    pause_job: {
+      reducerName: "pause_job",
+      argsType: PauseJob.getTypeScriptAlgebraicType(),
+    },
+    pin_filed_version: {
+      reducerName: "pin_filed_version",
+      argsType: PinFiledVersion.getTypeScriptAlgebraicType(),
+    },
+    purchase_office_action_extension: {
+      reducerName: "purchase_office_action_extension",
+      argsType: PurchaseOfficeActionExtension.getTypeScriptAlgebraicType(),
+    },
+    reclaim_doc_job_leases: {
+      reducerName: "reclaim_doc_job_leases",
+      argsType: ReclaimDocJobLeases.getTypeScriptAlgebraicType(),
+    },
+    record_grant: {
+      reducerName: "record_grant",
+      argsType: RecordGrant.getTypeScriptAlgebraicType(),
+    },
+    record_maintenance_payment: {
+      reducerName: "record_maintenance_payment",
+      argsType: RecordMaintenancePayment.getTypeScriptAlgebraicType(),
+    },
+    record_office_action: {
+      reducerName: "record_office_action",
+      argsType: RecordOfficeAction.getTypeScriptAlgebraicType(),
+    },
+    record_office_action_response: {
+      reducerName: "record_office_action_response",
+      argsType: RecordOfficeActionResponse.getTypeScriptAlgebraicType(),
+    },
+    regenerate_document: {
+      reducerName: "regenerate_document",
+      argsType: RegenerateDocument.getTypeScriptAlgebraicType(),
+    },
+    register_doc_worker: {
+      reducerName: "register_doc_worker",
+      argsType: RegisterDocWorker.getTypeScriptAlgebraicType(),
+    },
     register_inventor: {
       reducerName: "register_inventor",
       argsType: RegisterInventor.getTypeScriptAlgebraicType(),
     },
+    remove_admin: {
+      reducerName: "remove_admin",
+      argsType: RemoveAdmin.getTypeScriptAlgebraicType(),
+    },
+    remove_application_inventor: {
+      reducerName: "remove_application_inventor",
+      argsType: RemoveApplicationInventor.getTypeScriptAlgebraicType(),
+    },
+    remove_classification: {
+      reducerName: "remove_classification",
+      argsType: RemoveClassification.getTypeScriptAlgebraicType(),
+    },
+    remove_co_owner: {
+      reducerName: "remove_co_owner",
+      argsType: RemoveCoOwner.getTypeScriptAlgebraicType(),
+    },
+    remove_doc_worker: {
+      reducerName: "remove_doc_worker",
+      argsType: RemoveDocWorker.getTypeScriptAlgebraicType(),
+    },
+    remove_organization_member: {
+      reducerName: "remove_organization_member",
+      argsType: RemoveOrganizationMember.getTypeScriptAlgebraicType(),
+    },
+    request_batch_anchoring: {
+      reducerName: "request_batch_anchoring",
+      argsType: RequestBatchAnchoring.getTypeScriptAlgebraicType(),
+    },
     resolve_alert: {
       reducerName: "resolve_alert",
       argsType: ResolveAlert.getTypeScriptAlgebraicType(),
@@ -337,6 +1251,34 @@ const REMOTE_MODULE = {
       reducerName: "respond_connection",
       argsType: RespondConnection.getTypeScriptAlgebraicType(),
     },
+    resume_job: {
+      reducerName: "resume_job",
+      argsType: ResumeJob.getTypeScriptAlgebraicType(),
+    },
+    review_prior_art: {
+      reducerName: "review_prior_art",
+      argsType: ReviewPriorArt.getTypeScriptAlgebraicType(),
+    },
+    save_document_version: {
+      reducerName: "save_document_version",
+      argsType: SaveDocumentVersion.getTypeScriptAlgebraicType(),
+    },
+    set_application_inventor: {
+      reducerName: "set_application_inventor",
+      argsType: SetApplicationInventor.getTypeScriptAlgebraicType(),
+    },
+    set_filing_details: {
+      reducerName: "set_filing_details",
+      argsType: SetFilingDetails.getTypeScriptAlgebraicType(),
+    },
+    set_job_interval: {
+      reducerName: "set_job_interval",
+      argsType: SetJobInterval.getTypeScriptAlgebraicType(),
+    },
+    set_reminder_lead_days: {
+      reducerName: "set_reminder_lead_days",
+      argsType: SetReminderLeadDays.getTypeScriptAlgebraicType(),
+    },
     start_collab_session: {
       reducerName: "start_collab_session",
       argsType: StartCollabSession.getTypeScriptAlgebraicType(),
@@ -345,6 +1287,18 @@ const REMOTE_MODULE = {
       reducerName: "submit_patent",
       argsType: SubmitPatent.getTypeScriptAlgebraicType(),
     },
+    suggest_classifications: {
+      reducerName: "suggest_classifications",
+      argsType: SuggestClassifications.getTypeScriptAlgebraicType(),
+    },
+    unlink_family: {
+      reducerName: "unlink_family",
+      argsType: UnlinkFamily.getTypeScriptAlgebraicType(),
+    },
+    update_jurisdiction_status: {
+      reducerName: "update_jurisdiction_status",
+      argsType: UpdateJurisdictionStatus.getTypeScriptAlgebraicType(),
+    },
     update_patent_status: {
       reducerName: "update_patent_status",
       argsType: UpdatePatentStatus.getTypeScriptAlgebraicType(),
@@ -357,6 +1311,14 @@ const REMOTE_MODULE = {
       reducerName: "upsert_document_generation",
       argsType: UpsertDocumentGeneration.getTypeScriptAlgebraicType(),
     },
+    verify_merkle_inclusion: {
+      reducerName: "verify_merkle_inclusion",
+      argsType: VerifyMerkleInclusion.getTypeScriptAlgebraicType(),
+    },
+    verify_merkle_proof: {
+      reducerName: "verify_merkle_proof",
+      argsType: VerifyMerkleProof.getTypeScriptAlgebraicType(),
+    },
   },
   versionInfo: {
     cliVersion: "1.3.2",
@@ -387,111 +1349,436 @@ const REMOTE_MODULE = {
 
 // A type representing all the possible variants of a reducer.
 export type Reducer = never
-| { name: "AddBlockchainRecord", args: AddBlockchainRecord }
+| { name: "AcknowledgeReminder", args: AcknowledgeReminder }
+| { name: "AddAdmin", args: AddAdmin }
+| { name: "AddClassification", args: AddClassification }
+| { name: "AddCoOwner", args: AddCoOwner }
+| { name: "AddJurisdictionFiling", args: AddJurisdictionFiling }
+| { name: "AddOrganizationMember", args: AddOrganizationMember }
 | { name: "AddPriorArtResult", args: AddPriorArtResult }
 | { name: "AddToPortfolio", args: AddToPortfolio }
 | { name: "AnalyticsTick", args: AnalyticsTick }
+| { name: "AnchorMerkleBatch", args: AnchorMerkleBatch }
+| { name: "AnchorNotarization", args: AnchorNotarization }
+| { name: "AnnotatePriorArtCitation", args: AnnotatePriorArtCitation }
+| { name: "BootstrapModule", args: BootstrapModule }
+| { name: "CancelBatchAnchoring", args: CancelBatchAnchoring }
+| { name: "ClaimNextJob", args: ClaimNextJob }
 | { name: "CloseCollabSession", args: CloseCollabSession }
+| { name: "CompactionTick", args: CompactionTick }
+| { name: "CompleteDeadline", args: CompleteDeadline }
+| { name: "CompleteJob", args: CompleteJob }
+| { name: "ComputeDocumentDiff", args: ComputeDocumentDiff }
+| { name: "ConfirmClassification", args: ConfirmClassification }
+| { name: "ConfirmInventorship", args: ConfirmInventorship }
 | { name: "ConnectInventor", args: ConnectInventor }
+| { name: "CreateOrganization", args: CreateOrganization }
+| { name: "DisputeInventorship", args: DisputeInventorship }
+| { name: "DocketTick", args: DocketTick }
+| { name: "EnqueueDocJob", args: EnqueueDocJob }
+| { name: "ExportApplicationXml", args: ExportApplicationXml }
+| { name: "ExportPortfolio", args: ExportPortfolio }
+| { name: "FailJob", args: FailJob }
+| { name: "GenerateIds", args: GenerateIds }
+| { name: "HeartbeatJob", args: HeartbeatJob }
 | { name: "IdentityConnected", args: IdentityConnected }
 | { name: "IdentityDisconnected", args: IdentityDisconnected }
+| { name: "ImportApplicationXml", args: ImportApplicationXml }
+| { name: "ImportPortfolio", args: ImportPortfolio }
 | { name: "JoinCollabSession", args: JoinCollabSession }
 | { name: "LeaveCollabSession", args: LeaveCollabSession }
+| { name: "LinkFamily", args: LinkFamily }
+| { name: "MaintenanceTick", args: MaintenanceTick }
+| { name: "MerkleBatchTick", args: MerkleBatchTick }
 | { name: "MonitorTick", args: MonitorTick }
+| { name: "NotarizeApplication", args: NotarizeApplication }
+| { name: "PauseJob", args: PauseJob }
+| { name: "PinFiledVersion", args: PinFiledVersion }
+| { name: "PurchaseOfficeActionExtension", args: PurchaseOfficeActionExtension }
+| { name: "ReclaimDocJobLeases", args: ReclaimDocJobLeases }
+| { name: "RecordGrant", args: RecordGrant }
+| { name: "RecordMaintenancePayment", args: RecordMaintenancePayment }
+| { name: "RecordOfficeAction", args: RecordOfficeAction }
+| { name: "RecordOfficeActionResponse", args: RecordOfficeActionResponse }
+| { name: "RegenerateDocument", args: RegenerateDocument }
+| { name: "RegisterDocWorker", args: RegisterDocWorker }
 | { name: "RegisterInventor", args: RegisterInventor }
+| { name: "RemoveAdmin", args: RemoveAdmin }
+| { name: "RemoveApplicationInventor", args: RemoveApplicationInventor }
+| { name: "RemoveClassification", args: RemoveClassification }
+| { name: "RemoveCoOwner", args: RemoveCoOwner }
+| { name: "RemoveDocWorker", args: RemoveDocWorker }
+| { name: "RemoveOrganizationMember", args: RemoveOrganizationMember }
+| { name: "RequestBatchAnchoring", args: RequestBatchAnchoring }
 | { name: "ResolveAlert", args: ResolveAlert }
 | { name: "RespondConnection", args: RespondConnection }
+| { name: "ResumeJob", args: ResumeJob }
+| { name: "ReviewPriorArt", args: ReviewPriorArt }
+| { name: "SaveDocumentVersion", args: SaveDocumentVersion }
+| { name: "SetApplicationInventor", args: SetApplicationInventor }
+| { name: "SetFilingDetails", args: SetFilingDetails }
+| { name: "SetJobInterval", args: SetJobInterval }
+| { name: "SetReminderLeadDays", args: SetReminderLeadDays }
 | { name: "StartCollabSession", args: StartCollabSession }
 | { name: "SubmitPatent", args: SubmitPatent }
+| { name: "SuggestClassifications", args: SuggestClassifications }
+| { name: "UnlinkFamily", args: UnlinkFamily }
+| { name: "UpdateJurisdictionStatus", args: UpdateJurisdictionStatus }
 | { name: "UpdatePatentStatus", args: UpdatePatentStatus }
 | { name: "UpdateStageProgress", args: UpdateStageProgress }
 | { name: "UpsertDocumentGeneration", args: UpsertDocumentGeneration }
+| { name: "VerifyMerkleInclusion", args: VerifyMerkleInclusion }
+| { name: "VerifyMerkleProof", args: VerifyMerkleProof }
 ;
 
 export class RemoteReducers {
   constructor(private connection: DbConnectionImpl, private setCallReducerFlags: SetReducerFlags) {}
 
-  addBlockchainRecord(applicationId: bigint, txHash: string, network: string) {
-    const __args = { applicationId, txHash, network };
+  acknowledgeReminder(reminderId: bigint) {
+    const __args = { reminderId };
     let __writer = new BinaryWriter(1024);
-    AddBlockchainRecord.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    AcknowledgeReminder.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("add_blockchain_record", __argsBuffer, this.setCallReducerFlags.addBlockchainRecordFlags);
+    this.connection.callReducer("acknowledge_reminder", __argsBuffer, this.setCallReducerFlags.acknowledgeReminderFlags);
   }
 
-  onAddBlockchainRecord(callback: (ctx: ReducerEventContext, applicationId: bigint, txHash: string, network: string) => void) {
-    this.connection.onReducer("add_blockchain_record", callback);
+  onAcknowledgeReminder(callback: (ctx: ReducerEventContext, reminderId: bigint) => void) {
+    this.connection.onReducer("acknowledge_reminder", callback);
   }
 
-  removeOnAddBlockchainRecord(callback: (ctx: ReducerEventContext, applicationId: bigint, txHash: string, network: string) => void) {
-    this.connection.offReducer("add_blockchain_record", callback);
+  removeOnAcknowledgeReminder(callback: (ctx: ReducerEventContext, reminderId: bigint) => void) {
+    this.connection.offReducer("acknowledge_reminder", callback);
   }
 
-  addPriorArtResult(applicationId: bigint, source: string, url: string, summary: string, relevanceScore: number) {
-    const __args = { applicationId, source, url, summary, relevanceScore };
+  addAdmin(identity: Identity) {
+    const __args = { identity };
     let __writer = new BinaryWriter(1024);
-    AddPriorArtResult.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    AddAdmin.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("add_prior_art_result", __argsBuffer, this.setCallReducerFlags.addPriorArtResultFlags);
+    this.connection.callReducer("add_admin", __argsBuffer, this.setCallReducerFlags.addAdminFlags);
   }
 
-  onAddPriorArtResult(callback: (ctx: ReducerEventContext, applicationId: bigint, source: string, url: string, summary: string, relevanceScore: number) => void) {
-    this.connection.onReducer("add_prior_art_result", callback);
+  onAddAdmin(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("add_admin", callback);
   }
 
-  removeOnAddPriorArtResult(callback: (ctx: ReducerEventContext, applicationId: bigint, source: string, url: string, summary: string, relevanceScore: number) => void) {
-    this.connection.offReducer("add_prior_art_result", callback);
+  removeOnAddAdmin(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("add_admin", callback);
   }
 
-  addToPortfolio(applicationId: bigint, role: PortfolioRole) {
-    const __args = { applicationId, role };
+  addClassification(applicationId: bigint, scheme: ClassificationScheme, code: string, primary: boolean) {
+    const __args = { applicationId, scheme, code, primary };
     let __writer = new BinaryWriter(1024);
-    AddToPortfolio.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    AddClassification.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("add_to_portfolio", __argsBuffer, this.setCallReducerFlags.addToPortfolioFlags);
+    this.connection.callReducer("add_classification", __argsBuffer, this.setCallReducerFlags.addClassificationFlags);
   }
 
-  onAddToPortfolio(callback: (ctx: ReducerEventContext, applicationId: bigint, role: PortfolioRole) => void) {
-    this.connection.onReducer("add_to_portfolio", callback);
+  onAddClassification(callback: (ctx: ReducerEventContext, applicationId: bigint, scheme: ClassificationScheme, code: string, primary: boolean) => void) {
+    this.connection.onReducer("add_classification", callback);
   }
 
-  removeOnAddToPortfolio(callback: (ctx: ReducerEventContext, applicationId: bigint, role: PortfolioRole) => void) {
-    this.connection.offReducer("add_to_portfolio", callback);
+  removeOnAddClassification(callback: (ctx: ReducerEventContext, applicationId: bigint, scheme: ClassificationScheme, code: string, primary: boolean) => void) {
+    this.connection.offReducer("add_classification", callback);
   }
 
-  analyticsTick(info: AnalyticsSchedule) {
-    const __args = { info };
+  addCoOwner(applicationId: bigint, holder: Identity) {
+    const __args = { applicationId, holder };
     let __writer = new BinaryWriter(1024);
-    AnalyticsTick.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    AddCoOwner.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("analytics_tick", __argsBuffer, this.setCallReducerFlags.analyticsTickFlags);
+    this.connection.callReducer("add_co_owner", __argsBuffer, this.setCallReducerFlags.addCoOwnerFlags);
   }
 
-  onAnalyticsTick(callback: (ctx: ReducerEventContext, info: AnalyticsSchedule) => void) {
-    this.connection.onReducer("analytics_tick", callback);
+  onAddCoOwner(callback: (ctx: ReducerEventContext, applicationId: bigint, holder: Identity) => void) {
+    this.connection.onReducer("add_co_owner", callback);
   }
 
-  removeOnAnalyticsTick(callback: (ctx: ReducerEventContext, info: AnalyticsSchedule) => void) {
-    this.connection.offReducer("analytics_tick", callback);
+  removeOnAddCoOwner(callback: (ctx: ReducerEventContext, applicationId: bigint, holder: Identity) => void) {
+    this.connection.offReducer("add_co_owner", callback);
   }
 
-  closeCollabSession(sessionId: bigint) {
-    const __args = { sessionId };
+  addJurisdictionFiling(applicationId: bigint, office: PatentOffice, applicationNumber: string, filingDate: Timestamp) {
+    const __args = { applicationId, office, applicationNumber, filingDate };
     let __writer = new BinaryWriter(1024);
-    CloseCollabSession.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    AddJurisdictionFiling.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("close_collab_session", __argsBuffer, this.setCallReducerFlags.closeCollabSessionFlags);
+    this.connection.callReducer("add_jurisdiction_filing", __argsBuffer, this.setCallReducerFlags.addJurisdictionFilingFlags);
   }
 
-  onCloseCollabSession(callback: (ctx: ReducerEventContext, sessionId: bigint) => void) {
-    this.connection.onReducer("close_collab_session", callback);
+  onAddJurisdictionFiling(callback: (ctx: ReducerEventContext, applicationId: bigint, office: PatentOffice, applicationNumber: string, filingDate: Timestamp) => void) {
+    this.connection.onReducer("add_jurisdiction_filing", callback);
   }
 
-  removeOnCloseCollabSession(callback: (ctx: ReducerEventContext, sessionId: bigint) => void) {
-    this.connection.offReducer("close_collab_session", callback);
+  removeOnAddJurisdictionFiling(callback: (ctx: ReducerEventContext, applicationId: bigint, office: PatentOffice, applicationNumber: string, filingDate: Timestamp) => void) {
+    this.connection.offReducer("add_jurisdiction_filing", callback);
   }
 
-  connectInventor(target: Identity) {
+  addOrganizationMember(organization: string, member: Identity, manager: boolean) {
+    const __args = { organization, member, manager };
+    let __writer = new BinaryWriter(1024);
+    AddOrganizationMember.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("add_organization_member", __argsBuffer, this.setCallReducerFlags.addOrganizationMemberFlags);
+  }
+
+  onAddOrganizationMember(callback: (ctx: ReducerEventContext, organization: string, member: Identity, manager: boolean) => void) {
+    this.connection.onReducer("add_organization_member", callback);
+  }
+
+  removeOnAddOrganizationMember(callback: (ctx: ReducerEventContext, organization: string, member: Identity, manager: boolean) => void) {
+    this.connection.offReducer("add_organization_member", callback);
+  }
+
+  addPriorArtResult(applicationId: bigint, source: string, url: string, summary: string, relevanceScore: number) {
+    const __args = { applicationId, source, url, summary, relevanceScore };
+    let __writer = new BinaryWriter(1024);
+    AddPriorArtResult.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("add_prior_art_result", __argsBuffer, this.setCallReducerFlags.addPriorArtResultFlags);
+  }
+
+  onAddPriorArtResult(callback: (ctx: ReducerEventContext, applicationId: bigint, source: string, url: string, summary: string, relevanceScore: number) => void) {
+    this.connection.onReducer("add_prior_art_result", callback);
+  }
+
+  removeOnAddPriorArtResult(callback: (ctx: ReducerEventContext, applicationId: bigint, source: string, url: string, summary: string, relevanceScore: number) => void) {
+    this.connection.offReducer("add_prior_art_result", callback);
+  }
+
+  addToPortfolio(applicationId: bigint, role: PortfolioRole) {
+    const __args = { applicationId, role };
+    let __writer = new BinaryWriter(1024);
+    AddToPortfolio.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("add_to_portfolio", __argsBuffer, this.setCallReducerFlags.addToPortfolioFlags);
+  }
+
+  onAddToPortfolio(callback: (ctx: ReducerEventContext, applicationId: bigint, role: PortfolioRole) => void) {
+    this.connection.onReducer("add_to_portfolio", callback);
+  }
+
+  removeOnAddToPortfolio(callback: (ctx: ReducerEventContext, applicationId: bigint, role: PortfolioRole) => void) {
+    this.connection.offReducer("add_to_portfolio", callback);
+  }
+
+  analyticsTick(info: AnalyticsSchedule) {
+    const __args = { info };
+    let __writer = new BinaryWriter(1024);
+    AnalyticsTick.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("analytics_tick", __argsBuffer, this.setCallReducerFlags.analyticsTickFlags);
+  }
+
+  onAnalyticsTick(callback: (ctx: ReducerEventContext, info: AnalyticsSchedule) => void) {
+    this.connection.onReducer("analytics_tick", callback);
+  }
+
+  removeOnAnalyticsTick(callback: (ctx: ReducerEventContext, info: AnalyticsSchedule) => void) {
+    this.connection.offReducer("analytics_tick", callback);
+  }
+
+  anchorMerkleBatch(batchId: bigint, txHash: string, network: string) {
+    const __args = { batchId, txHash, network };
+    let __writer = new BinaryWriter(1024);
+    AnchorMerkleBatch.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("anchor_merkle_batch", __argsBuffer, this.setCallReducerFlags.anchorMerkleBatchFlags);
+  }
+
+  onAnchorMerkleBatch(callback: (ctx: ReducerEventContext, batchId: bigint, txHash: string, network: string) => void) {
+    this.connection.onReducer("anchor_merkle_batch", callback);
+  }
+
+  removeOnAnchorMerkleBatch(callback: (ctx: ReducerEventContext, batchId: bigint, txHash: string, network: string) => void) {
+    this.connection.offReducer("anchor_merkle_batch", callback);
+  }
+
+  anchorNotarization(notarizationId: bigint, txHash: string, network: string) {
+    const __args = { notarizationId, txHash, network };
+    let __writer = new BinaryWriter(1024);
+    AnchorNotarization.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("anchor_notarization", __argsBuffer, this.setCallReducerFlags.anchorNotarizationFlags);
+  }
+
+  onAnchorNotarization(callback: (ctx: ReducerEventContext, notarizationId: bigint, txHash: string, network: string) => void) {
+    this.connection.onReducer("anchor_notarization", callback);
+  }
+
+  removeOnAnchorNotarization(callback: (ctx: ReducerEventContext, notarizationId: bigint, txHash: string, network: string) => void) {
+    this.connection.offReducer("anchor_notarization", callback);
+  }
+
+  annotatePriorArtCitation(resultId: bigint, publicationNumber: string, publicationDate: Timestamp | undefined) {
+    const __args = { resultId, publicationNumber, publicationDate };
+    let __writer = new BinaryWriter(1024);
+    AnnotatePriorArtCitation.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("annotate_prior_art_citation", __argsBuffer, this.setCallReducerFlags.annotatePriorArtCitationFlags);
+  }
+
+  onAnnotatePriorArtCitation(callback: (ctx: ReducerEventContext, resultId: bigint, publicationNumber: string, publicationDate: Timestamp | undefined) => void) {
+    this.connection.onReducer("annotate_prior_art_citation", callback);
+  }
+
+  removeOnAnnotatePriorArtCitation(callback: (ctx: ReducerEventContext, resultId: bigint, publicationNumber: string, publicationDate: Timestamp | undefined) => void) {
+    this.connection.offReducer("annotate_prior_art_citation", callback);
+  }
+
+  bootstrapModule() {
+    this.connection.callReducer("bootstrap_module", new Uint8Array(0), this.setCallReducerFlags.bootstrapModuleFlags);
+  }
+
+  onBootstrapModule(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("bootstrap_module", callback);
+  }
+
+  removeOnBootstrapModule(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("bootstrap_module", callback);
+  }
+
+  cancelBatchAnchoring(notarizationId: bigint) {
+    const __args = { notarizationId };
+    let __writer = new BinaryWriter(1024);
+    CancelBatchAnchoring.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("cancel_batch_anchoring", __argsBuffer, this.setCallReducerFlags.cancelBatchAnchoringFlags);
+  }
+
+  onCancelBatchAnchoring(callback: (ctx: ReducerEventContext, notarizationId: bigint) => void) {
+    this.connection.onReducer("cancel_batch_anchoring", callback);
+  }
+
+  removeOnCancelBatchAnchoring(callback: (ctx: ReducerEventContext, notarizationId: bigint) => void) {
+    this.connection.offReducer("cancel_batch_anchoring", callback);
+  }
+
+  claimNextJob() {
+    this.connection.callReducer("claim_next_job", new Uint8Array(0), this.setCallReducerFlags.claimNextJobFlags);
+  }
+
+  onClaimNextJob(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("claim_next_job", callback);
+  }
+
+  removeOnClaimNextJob(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("claim_next_job", callback);
+  }
+
+  closeCollabSession(sessionId: bigint) {
+    const __args = { sessionId };
+    let __writer = new BinaryWriter(1024);
+    CloseCollabSession.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("close_collab_session", __argsBuffer, this.setCallReducerFlags.closeCollabSessionFlags);
+  }
+
+  onCloseCollabSession(callback: (ctx: ReducerEventContext, sessionId: bigint) => void) {
+    this.connection.onReducer("close_collab_session", callback);
+  }
+
+  removeOnCloseCollabSession(callback: (ctx: ReducerEventContext, sessionId: bigint) => void) {
+    this.connection.offReducer("close_collab_session", callback);
+  }
+
+  compactionTick(info: CompactionSchedule) {
+    const __args = { info };
+    let __writer = new BinaryWriter(1024);
+    CompactionTick.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("compaction_tick", __argsBuffer, this.setCallReducerFlags.compactionTickFlags);
+  }
+
+  onCompactionTick(callback: (ctx: ReducerEventContext, info: CompactionSchedule) => void) {
+    this.connection.onReducer("compaction_tick", callback);
+  }
+
+  removeOnCompactionTick(callback: (ctx: ReducerEventContext, info: CompactionSchedule) => void) {
+    this.connection.offReducer("compaction_tick", callback);
+  }
+
+  completeDeadline(deadlineId: bigint) {
+    const __args = { deadlineId };
+    let __writer = new BinaryWriter(1024);
+    CompleteDeadline.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("complete_deadline", __argsBuffer, this.setCallReducerFlags.completeDeadlineFlags);
+  }
+
+  onCompleteDeadline(callback: (ctx: ReducerEventContext, deadlineId: bigint) => void) {
+    this.connection.onReducer("complete_deadline", callback);
+  }
+
+  removeOnCompleteDeadline(callback: (ctx: ReducerEventContext, deadlineId: bigint) => void) {
+    this.connection.offReducer("complete_deadline", callback);
+  }
+
+  completeJob(jobId: bigint, content: string, generator: string) {
+    const __args = { jobId, content, generator };
+    let __writer = new BinaryWriter(1024);
+    CompleteJob.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("complete_job", __argsBuffer, this.setCallReducerFlags.completeJobFlags);
+  }
+
+  onCompleteJob(callback: (ctx: ReducerEventContext, jobId: bigint, content: string, generator: string) => void) {
+    this.connection.onReducer("complete_job", callback);
+  }
+
+  removeOnCompleteJob(callback: (ctx: ReducerEventContext, jobId: bigint, content: string, generator: string) => void) {
+    this.connection.offReducer("complete_job", callback);
+  }
+
+  computeDocumentDiff(fromVersionId: bigint, toVersionId: bigint) {
+    const __args = { fromVersionId, toVersionId };
+    let __writer = new BinaryWriter(1024);
+    ComputeDocumentDiff.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("compute_document_diff", __argsBuffer, this.setCallReducerFlags.computeDocumentDiffFlags);
+  }
+
+  onComputeDocumentDiff(callback: (ctx: ReducerEventContext, fromVersionId: bigint, toVersionId: bigint) => void) {
+    this.connection.onReducer("compute_document_diff", callback);
+  }
+
+  removeOnComputeDocumentDiff(callback: (ctx: ReducerEventContext, fromVersionId: bigint, toVersionId: bigint) => void) {
+    this.connection.offReducer("compute_document_diff", callback);
+  }
+
+  confirmClassification(classificationId: bigint, primary: boolean) {
+    const __args = { classificationId, primary };
+    let __writer = new BinaryWriter(1024);
+    ConfirmClassification.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("confirm_classification", __argsBuffer, this.setCallReducerFlags.confirmClassificationFlags);
+  }
+
+  onConfirmClassification(callback: (ctx: ReducerEventContext, classificationId: bigint, primary: boolean) => void) {
+    this.connection.onReducer("confirm_classification", callback);
+  }
+
+  removeOnConfirmClassification(callback: (ctx: ReducerEventContext, classificationId: bigint, primary: boolean) => void) {
+    this.connection.offReducer("confirm_classification", callback);
+  }
+
+  confirmInventorship(applicationId: bigint) {
+    const __args = { applicationId };
+    let __writer = new BinaryWriter(1024);
+    ConfirmInventorship.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("confirm_inventorship", __argsBuffer, this.setCallReducerFlags.confirmInventorshipFlags);
+  }
+
+  onConfirmInventorship(callback: (ctx: ReducerEventContext, applicationId: bigint) => void) {
+    this.connection.onReducer("confirm_inventorship", callback);
+  }
+
+  removeOnConfirmInventorship(callback: (ctx: ReducerEventContext, applicationId: bigint) => void) {
+    this.connection.offReducer("confirm_inventorship", callback);
+  }
+
+  connectInventor(target: Identity) {
     const __args = { target };
     let __writer = new BinaryWriter(1024);
     ConnectInventor.getTypeScriptAlgebraicType().serialize(__writer, __args);
@@ -507,6 +1794,146 @@ export class RemoteReducers {
     this.connection.offReducer("connect_inventor", callback);
   }
 
+  createOrganization(name: string) {
+    const __args = { name };
+    let __writer = new BinaryWriter(1024);
+    CreateOrganization.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_organization", __argsBuffer, this.setCallReducerFlags.createOrganizationFlags);
+  }
+
+  onCreateOrganization(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.onReducer("create_organization", callback);
+  }
+
+  removeOnCreateOrganization(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.offReducer("create_organization", callback);
+  }
+
+  disputeInventorship(applicationId: bigint, reason: string) {
+    const __args = { applicationId, reason };
+    let __writer = new BinaryWriter(1024);
+    DisputeInventorship.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("dispute_inventorship", __argsBuffer, this.setCallReducerFlags.disputeInventorshipFlags);
+  }
+
+  onDisputeInventorship(callback: (ctx: ReducerEventContext, applicationId: bigint, reason: string) => void) {
+    this.connection.onReducer("dispute_inventorship", callback);
+  }
+
+  removeOnDisputeInventorship(callback: (ctx: ReducerEventContext, applicationId: bigint, reason: string) => void) {
+    this.connection.offReducer("dispute_inventorship", callback);
+  }
+
+  docketTick(info: DocketSchedule) {
+    const __args = { info };
+    let __writer = new BinaryWriter(1024);
+    DocketTick.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("docket_tick", __argsBuffer, this.setCallReducerFlags.docketTickFlags);
+  }
+
+  onDocketTick(callback: (ctx: ReducerEventContext, info: DocketSchedule) => void) {
+    this.connection.onReducer("docket_tick", callback);
+  }
+
+  removeOnDocketTick(callback: (ctx: ReducerEventContext, info: DocketSchedule) => void) {
+    this.connection.offReducer("docket_tick", callback);
+  }
+
+  enqueueDocJob(applicationId: bigint, docType: DocType, priority: number) {
+    const __args = { applicationId, docType, priority };
+    let __writer = new BinaryWriter(1024);
+    EnqueueDocJob.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("enqueue_doc_job", __argsBuffer, this.setCallReducerFlags.enqueueDocJobFlags);
+  }
+
+  onEnqueueDocJob(callback: (ctx: ReducerEventContext, applicationId: bigint, docType: DocType, priority: number) => void) {
+    this.connection.onReducer("enqueue_doc_job", callback);
+  }
+
+  removeOnEnqueueDocJob(callback: (ctx: ReducerEventContext, applicationId: bigint, docType: DocType, priority: number) => void) {
+    this.connection.offReducer("enqueue_doc_job", callback);
+  }
+
+  exportApplicationXml(applicationId: bigint) {
+    const __args = { applicationId };
+    let __writer = new BinaryWriter(1024);
+    ExportApplicationXml.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("export_application_xml", __argsBuffer, this.setCallReducerFlags.exportApplicationXmlFlags);
+  }
+
+  onExportApplicationXml(callback: (ctx: ReducerEventContext, applicationId: bigint) => void) {
+    this.connection.onReducer("export_application_xml", callback);
+  }
+
+  removeOnExportApplicationXml(callback: (ctx: ReducerEventContext, applicationId: bigint) => void) {
+    this.connection.offReducer("export_application_xml", callback);
+  }
+
+  exportPortfolio() {
+    this.connection.callReducer("export_portfolio", new Uint8Array(0), this.setCallReducerFlags.exportPortfolioFlags);
+  }
+
+  onExportPortfolio(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("export_portfolio", callback);
+  }
+
+  removeOnExportPortfolio(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("export_portfolio", callback);
+  }
+
+  failJob(jobId: bigint, error: string) {
+    const __args = { jobId, error };
+    let __writer = new BinaryWriter(1024);
+    FailJob.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("fail_job", __argsBuffer, this.setCallReducerFlags.failJobFlags);
+  }
+
+  onFailJob(callback: (ctx: ReducerEventContext, jobId: bigint, error: string) => void) {
+    this.connection.onReducer("fail_job", callback);
+  }
+
+  removeOnFailJob(callback: (ctx: ReducerEventContext, jobId: bigint, error: string) => void) {
+    this.connection.offReducer("fail_job", callback);
+  }
+
+  generateIds(applicationId: bigint) {
+    const __args = { applicationId };
+    let __writer = new BinaryWriter(1024);
+    GenerateIds.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("generate_ids", __argsBuffer, this.setCallReducerFlags.generateIdsFlags);
+  }
+
+  onGenerateIds(callback: (ctx: ReducerEventContext, applicationId: bigint) => void) {
+    this.connection.onReducer("generate_ids", callback);
+  }
+
+  removeOnGenerateIds(callback: (ctx: ReducerEventContext, applicationId: bigint) => void) {
+    this.connection.offReducer("generate_ids", callback);
+  }
+
+  heartbeatJob(jobId: bigint) {
+    const __args = { jobId };
+    let __writer = new BinaryWriter(1024);
+    HeartbeatJob.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("heartbeat_job", __argsBuffer, this.setCallReducerFlags.heartbeatJobFlags);
+  }
+
+  onHeartbeatJob(callback: (ctx: ReducerEventContext, jobId: bigint) => void) {
+    this.connection.onReducer("heartbeat_job", callback);
+  }
+
+  removeOnHeartbeatJob(callback: (ctx: ReducerEventContext, jobId: bigint) => void) {
+    this.connection.offReducer("heartbeat_job", callback);
+  }
+
   onIdentityConnected(callback: (ctx: ReducerEventContext) => void) {
     this.connection.onReducer("identity_connected", callback);
   }
@@ -523,6 +1950,38 @@ export class RemoteReducers {
     this.connection.offReducer("identity_disconnected", callback);
   }
 
+  importApplicationXml(xml: string) {
+    const __args = { xml };
+    let __writer = new BinaryWriter(1024);
+    ImportApplicationXml.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("import_application_xml", __argsBuffer, this.setCallReducerFlags.importApplicationXmlFlags);
+  }
+
+  onImportApplicationXml(callback: (ctx: ReducerEventContext, xml: string) => void) {
+    this.connection.onReducer("import_application_xml", callback);
+  }
+
+  removeOnImportApplicationXml(callback: (ctx: ReducerEventContext, xml: string) => void) {
+    this.connection.offReducer("import_application_xml", callback);
+  }
+
+  importPortfolio(format: ImportFormat, payload: string) {
+    const __args = { format, payload };
+    let __writer = new BinaryWriter(1024);
+    ImportPortfolio.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("import_portfolio", __argsBuffer, this.setCallReducerFlags.importPortfolioFlags);
+  }
+
+  onImportPortfolio(callback: (ctx: ReducerEventContext, format: ImportFormat, payload: string) => void) {
+    this.connection.onReducer("import_portfolio", callback);
+  }
+
+  removeOnImportPortfolio(callback: (ctx: ReducerEventContext, format: ImportFormat, payload: string) => void) {
+    this.connection.offReducer("import_portfolio", callback);
+  }
+
   joinCollabSession(sessionId: bigint) {
     const __args = { sessionId };
     let __writer = new BinaryWriter(1024);
@@ -555,6 +2014,54 @@ export class RemoteReducers {
     this.connection.offReducer("leave_collab_session", callback);
   }
 
+  linkFamily(parentApplicationId: bigint, childApplicationId: bigint, relationship: FamilyRelationship, priorityDate: Timestamp) {
+    const __args = { parentApplicationId, childApplicationId, relationship, priorityDate };
+    let __writer = new BinaryWriter(1024);
+    LinkFamily.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("link_family", __argsBuffer, this.setCallReducerFlags.linkFamilyFlags);
+  }
+
+  onLinkFamily(callback: (ctx: ReducerEventContext, parentApplicationId: bigint, childApplicationId: bigint, relationship: FamilyRelationship, priorityDate: Timestamp) => void) {
+    this.connection.onReducer("link_family", callback);
+  }
+
+  removeOnLinkFamily(callback: (ctx: ReducerEventContext, parentApplicationId: bigint, childApplicationId: bigint, relationship: FamilyRelationship, priorityDate: Timestamp) => void) {
+    this.connection.offReducer("link_family", callback);
+  }
+
+  maintenanceTick(info: MaintenanceSchedule) {
+    const __args = { info };
+    let __writer = new BinaryWriter(1024);
+    MaintenanceTick.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("maintenance_tick", __argsBuffer, this.setCallReducerFlags.maintenanceTickFlags);
+  }
+
+  onMaintenanceTick(callback: (ctx: ReducerEventContext, info: MaintenanceSchedule) => void) {
+    this.connection.onReducer("maintenance_tick", callback);
+  }
+
+  removeOnMaintenanceTick(callback: (ctx: ReducerEventContext, info: MaintenanceSchedule) => void) {
+    this.connection.offReducer("maintenance_tick", callback);
+  }
+
+  merkleBatchTick(info: MerkleSchedule) {
+    const __args = { info };
+    let __writer = new BinaryWriter(1024);
+    MerkleBatchTick.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("merkle_batch_tick", __argsBuffer, this.setCallReducerFlags.merkleBatchTickFlags);
+  }
+
+  onMerkleBatchTick(callback: (ctx: ReducerEventContext, info: MerkleSchedule) => void) {
+    this.connection.onReducer("merkle_batch_tick", callback);
+  }
+
+  removeOnMerkleBatchTick(callback: (ctx: ReducerEventContext, info: MerkleSchedule) => void) {
+    this.connection.offReducer("merkle_batch_tick", callback);
+  }
+
   monitorTick(info: MonitoringSchedule) {
     const __args = { info };
     let __writer = new BinaryWriter(1024);
@@ -571,6 +2078,182 @@ export class RemoteReducers {
     this.connection.offReducer("monitor_tick", callback);
   }
 
+  notarizeApplication(applicationId: bigint) {
+    const __args = { applicationId };
+    let __writer = new BinaryWriter(1024);
+    NotarizeApplication.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("notarize_application", __argsBuffer, this.setCallReducerFlags.notarizeApplicationFlags);
+  }
+
+  onNotarizeApplication(callback: (ctx: ReducerEventContext, applicationId: bigint) => void) {
+    this.connection.onReducer("notarize_application", callback);
+  }
+
+  removeOnNotarizeApplication(callback: (ctx: ReducerEventContext, applicationId: bigint) => void) {
+    this.connection.offReducer("notarize_application", callback);
+  }
+
+  pauseJob(job: string) {
+    const __args = { job };
+    let __writer = new BinaryWriter(1024);
+    PauseJob.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("pause_job", __argsBuffer, this.setCallReducerFlags.pauseJobFlags);
+  }
+
+  onPauseJob(callback: (ctx: ReducerEventContext, job: string) => void) {
+    this.connection.onReducer("pause_job", callback);
+  }
+
+  removeOnPauseJob(callback: (ctx: ReducerEventContext, job: string) => void) {
+    this.connection.offReducer("pause_job", callback);
+  }
+
+  pinFiledVersion(versionId: bigint) {
+    const __args = { versionId };
+    let __writer = new BinaryWriter(1024);
+    PinFiledVersion.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("pin_filed_version", __argsBuffer, this.setCallReducerFlags.pinFiledVersionFlags);
+  }
+
+  onPinFiledVersion(callback: (ctx: ReducerEventContext, versionId: bigint) => void) {
+    this.connection.onReducer("pin_filed_version", callback);
+  }
+
+  removeOnPinFiledVersion(callback: (ctx: ReducerEventContext, versionId: bigint) => void) {
+    this.connection.offReducer("pin_filed_version", callback);
+  }
+
+  purchaseOfficeActionExtension(actionId: bigint, months: number, entity: EntitySize) {
+    const __args = { actionId, months, entity };
+    let __writer = new BinaryWriter(1024);
+    PurchaseOfficeActionExtension.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("purchase_office_action_extension", __argsBuffer, this.setCallReducerFlags.purchaseOfficeActionExtensionFlags);
+  }
+
+  onPurchaseOfficeActionExtension(callback: (ctx: ReducerEventContext, actionId: bigint, months: number, entity: EntitySize) => void) {
+    this.connection.onReducer("purchase_office_action_extension", callback);
+  }
+
+  removeOnPurchaseOfficeActionExtension(callback: (ctx: ReducerEventContext, actionId: bigint, months: number, entity: EntitySize) => void) {
+    this.connection.offReducer("purchase_office_action_extension", callback);
+  }
+
+  reclaimDocJobLeases(info: DocLeaseSchedule) {
+    const __args = { info };
+    let __writer = new BinaryWriter(1024);
+    ReclaimDocJobLeases.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("reclaim_doc_job_leases", __argsBuffer, this.setCallReducerFlags.reclaimDocJobLeasesFlags);
+  }
+
+  onReclaimDocJobLeases(callback: (ctx: ReducerEventContext, info: DocLeaseSchedule) => void) {
+    this.connection.onReducer("reclaim_doc_job_leases", callback);
+  }
+
+  removeOnReclaimDocJobLeases(callback: (ctx: ReducerEventContext, info: DocLeaseSchedule) => void) {
+    this.connection.offReducer("reclaim_doc_job_leases", callback);
+  }
+
+  recordGrant(applicationId: bigint, patentNumber: string, grantDate: Timestamp, expiryDate: Timestamp | undefined) {
+    const __args = { applicationId, patentNumber, grantDate, expiryDate };
+    let __writer = new BinaryWriter(1024);
+    RecordGrant.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("record_grant", __argsBuffer, this.setCallReducerFlags.recordGrantFlags);
+  }
+
+  onRecordGrant(callback: (ctx: ReducerEventContext, applicationId: bigint, patentNumber: string, grantDate: Timestamp, expiryDate: Timestamp | undefined) => void) {
+    this.connection.onReducer("record_grant", callback);
+  }
+
+  removeOnRecordGrant(callback: (ctx: ReducerEventContext, applicationId: bigint, patentNumber: string, grantDate: Timestamp, expiryDate: Timestamp | undefined) => void) {
+    this.connection.offReducer("record_grant", callback);
+  }
+
+  recordMaintenancePayment(feeId: bigint, entity: EntitySize, paidAt: Timestamp) {
+    const __args = { feeId, entity, paidAt };
+    let __writer = new BinaryWriter(1024);
+    RecordMaintenancePayment.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("record_maintenance_payment", __argsBuffer, this.setCallReducerFlags.recordMaintenancePaymentFlags);
+  }
+
+  onRecordMaintenancePayment(callback: (ctx: ReducerEventContext, feeId: bigint, entity: EntitySize, paidAt: Timestamp) => void) {
+    this.connection.onReducer("record_maintenance_payment", callback);
+  }
+
+  removeOnRecordMaintenancePayment(callback: (ctx: ReducerEventContext, feeId: bigint, entity: EntitySize, paidAt: Timestamp) => void) {
+    this.connection.offReducer("record_maintenance_payment", callback);
+  }
+
+  recordOfficeAction(applicationId: bigint, actionType: OfficeActionType, mailedAt: Timestamp) {
+    const __args = { applicationId, actionType, mailedAt };
+    let __writer = new BinaryWriter(1024);
+    RecordOfficeAction.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("record_office_action", __argsBuffer, this.setCallReducerFlags.recordOfficeActionFlags);
+  }
+
+  onRecordOfficeAction(callback: (ctx: ReducerEventContext, applicationId: bigint, actionType: OfficeActionType, mailedAt: Timestamp) => void) {
+    this.connection.onReducer("record_office_action", callback);
+  }
+
+  removeOnRecordOfficeAction(callback: (ctx: ReducerEventContext, applicationId: bigint, actionType: OfficeActionType, mailedAt: Timestamp) => void) {
+    this.connection.offReducer("record_office_action", callback);
+  }
+
+  recordOfficeActionResponse(actionId: bigint, filedAt: Timestamp) {
+    const __args = { actionId, filedAt };
+    let __writer = new BinaryWriter(1024);
+    RecordOfficeActionResponse.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("record_office_action_response", __argsBuffer, this.setCallReducerFlags.recordOfficeActionResponseFlags);
+  }
+
+  onRecordOfficeActionResponse(callback: (ctx: ReducerEventContext, actionId: bigint, filedAt: Timestamp) => void) {
+    this.connection.onReducer("record_office_action_response", callback);
+  }
+
+  removeOnRecordOfficeActionResponse(callback: (ctx: ReducerEventContext, actionId: bigint, filedAt: Timestamp) => void) {
+    this.connection.offReducer("record_office_action_response", callback);
+  }
+
+  regenerateDocument(applicationId: bigint, docType: DocType, priority: number) {
+    const __args = { applicationId, docType, priority };
+    let __writer = new BinaryWriter(1024);
+    RegenerateDocument.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("regenerate_document", __argsBuffer, this.setCallReducerFlags.regenerateDocumentFlags);
+  }
+
+  onRegenerateDocument(callback: (ctx: ReducerEventContext, applicationId: bigint, docType: DocType, priority: number) => void) {
+    this.connection.onReducer("regenerate_document", callback);
+  }
+
+  removeOnRegenerateDocument(callback: (ctx: ReducerEventContext, applicationId: bigint, docType: DocType, priority: number) => void) {
+    this.connection.offReducer("regenerate_document", callback);
+  }
+
+  registerDocWorker(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    RegisterDocWorker.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("register_doc_worker", __argsBuffer, this.setCallReducerFlags.registerDocWorkerFlags);
+  }
+
+  onRegisterDocWorker(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("register_doc_worker", callback);
+  }
+
+  removeOnRegisterDocWorker(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("register_doc_worker", callback);
+  }
+
   registerInventor(name: string, email: string, affiliation: string, skills: string, bio: string) {
     const __args = { name, email, affiliation, skills, bio };
     let __writer = new BinaryWriter(1024);
@@ -587,6 +2270,118 @@ export class RemoteReducers {
     this.connection.offReducer("register_inventor", callback);
   }
 
+  removeAdmin(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    RemoveAdmin.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_admin", __argsBuffer, this.setCallReducerFlags.removeAdminFlags);
+  }
+
+  onRemoveAdmin(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("remove_admin", callback);
+  }
+
+  removeOnRemoveAdmin(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("remove_admin", callback);
+  }
+
+  removeApplicationInventor(applicationId: bigint, inventor: Identity) {
+    const __args = { applicationId, inventor };
+    let __writer = new BinaryWriter(1024);
+    RemoveApplicationInventor.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_application_inventor", __argsBuffer, this.setCallReducerFlags.removeApplicationInventorFlags);
+  }
+
+  onRemoveApplicationInventor(callback: (ctx: ReducerEventContext, applicationId: bigint, inventor: Identity) => void) {
+    this.connection.onReducer("remove_application_inventor", callback);
+  }
+
+  removeOnRemoveApplicationInventor(callback: (ctx: ReducerEventContext, applicationId: bigint, inventor: Identity) => void) {
+    this.connection.offReducer("remove_application_inventor", callback);
+  }
+
+  removeClassification(classificationId: bigint) {
+    const __args = { classificationId };
+    let __writer = new BinaryWriter(1024);
+    RemoveClassification.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_classification", __argsBuffer, this.setCallReducerFlags.removeClassificationFlags);
+  }
+
+  onRemoveClassification(callback: (ctx: ReducerEventContext, classificationId: bigint) => void) {
+    this.connection.onReducer("remove_classification", callback);
+  }
+
+  removeOnRemoveClassification(callback: (ctx: ReducerEventContext, classificationId: bigint) => void) {
+    this.connection.offReducer("remove_classification", callback);
+  }
+
+  removeCoOwner(applicationId: bigint, holder: Identity) {
+    const __args = { applicationId, holder };
+    let __writer = new BinaryWriter(1024);
+    RemoveCoOwner.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_co_owner", __argsBuffer, this.setCallReducerFlags.removeCoOwnerFlags);
+  }
+
+  onRemoveCoOwner(callback: (ctx: ReducerEventContext, applicationId: bigint, holder: Identity) => void) {
+    this.connection.onReducer("remove_co_owner", callback);
+  }
+
+  removeOnRemoveCoOwner(callback: (ctx: ReducerEventContext, applicationId: bigint, holder: Identity) => void) {
+    this.connection.offReducer("remove_co_owner", callback);
+  }
+
+  removeDocWorker(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    RemoveDocWorker.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_doc_worker", __argsBuffer, this.setCallReducerFlags.removeDocWorkerFlags);
+  }
+
+  onRemoveDocWorker(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("remove_doc_worker", callback);
+  }
+
+  removeOnRemoveDocWorker(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("remove_doc_worker", callback);
+  }
+
+  removeOrganizationMember(organization: string, member: Identity) {
+    const __args = { organization, member };
+    let __writer = new BinaryWriter(1024);
+    RemoveOrganizationMember.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_organization_member", __argsBuffer, this.setCallReducerFlags.removeOrganizationMemberFlags);
+  }
+
+  onRemoveOrganizationMember(callback: (ctx: ReducerEventContext, organization: string, member: Identity) => void) {
+    this.connection.onReducer("remove_organization_member", callback);
+  }
+
+  removeOnRemoveOrganizationMember(callback: (ctx: ReducerEventContext, organization: string, member: Identity) => void) {
+    this.connection.offReducer("remove_organization_member", callback);
+  }
+
+  requestBatchAnchoring(notarizationId: bigint) {
+    const __args = { notarizationId };
+    let __writer = new BinaryWriter(1024);
+    RequestBatchAnchoring.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("request_batch_anchoring", __argsBuffer, this.setCallReducerFlags.requestBatchAnchoringFlags);
+  }
+
+  onRequestBatchAnchoring(callback: (ctx: ReducerEventContext, notarizationId: bigint) => void) {
+    this.connection.onReducer("request_batch_anchoring", callback);
+  }
+
+  removeOnRequestBatchAnchoring(callback: (ctx: ReducerEventContext, notarizationId: bigint) => void) {
+    this.connection.offReducer("request_batch_anchoring", callback);
+  }
+
   resolveAlert(alertId: bigint) {
     const __args = { alertId };
     let __writer = new BinaryWriter(1024);
@@ -599,24 +2394,136 @@ export class RemoteReducers {
     this.connection.onReducer("resolve_alert", callback);
   }
 
-  removeOnResolveAlert(callback: (ctx: ReducerEventContext, alertId: bigint) => void) {
-    this.connection.offReducer("resolve_alert", callback);
+  removeOnResolveAlert(callback: (ctx: ReducerEventContext, alertId: bigint) => void) {
+    this.connection.offReducer("resolve_alert", callback);
+  }
+
+  respondConnection(connectionId: bigint, accept: boolean) {
+    const __args = { connectionId, accept };
+    let __writer = new BinaryWriter(1024);
+    RespondConnection.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("respond_connection", __argsBuffer, this.setCallReducerFlags.respondConnectionFlags);
+  }
+
+  onRespondConnection(callback: (ctx: ReducerEventContext, connectionId: bigint, accept: boolean) => void) {
+    this.connection.onReducer("respond_connection", callback);
+  }
+
+  removeOnRespondConnection(callback: (ctx: ReducerEventContext, connectionId: bigint, accept: boolean) => void) {
+    this.connection.offReducer("respond_connection", callback);
+  }
+
+  resumeJob(job: string) {
+    const __args = { job };
+    let __writer = new BinaryWriter(1024);
+    ResumeJob.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("resume_job", __argsBuffer, this.setCallReducerFlags.resumeJobFlags);
+  }
+
+  onResumeJob(callback: (ctx: ReducerEventContext, job: string) => void) {
+    this.connection.onReducer("resume_job", callback);
+  }
+
+  removeOnResumeJob(callback: (ctx: ReducerEventContext, job: string) => void) {
+    this.connection.offReducer("resume_job", callback);
+  }
+
+  reviewPriorArt(resultId: bigint) {
+    const __args = { resultId };
+    let __writer = new BinaryWriter(1024);
+    ReviewPriorArt.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("review_prior_art", __argsBuffer, this.setCallReducerFlags.reviewPriorArtFlags);
+  }
+
+  onReviewPriorArt(callback: (ctx: ReducerEventContext, resultId: bigint) => void) {
+    this.connection.onReducer("review_prior_art", callback);
+  }
+
+  removeOnReviewPriorArt(callback: (ctx: ReducerEventContext, resultId: bigint) => void) {
+    this.connection.offReducer("review_prior_art", callback);
+  }
+
+  saveDocumentVersion(applicationId: bigint, docType: DocType, content: string, generator: string, parentVersionId: bigint | undefined) {
+    const __args = { applicationId, docType, content, generator, parentVersionId };
+    let __writer = new BinaryWriter(1024);
+    SaveDocumentVersion.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("save_document_version", __argsBuffer, this.setCallReducerFlags.saveDocumentVersionFlags);
+  }
+
+  onSaveDocumentVersion(callback: (ctx: ReducerEventContext, applicationId: bigint, docType: DocType, content: string, generator: string, parentVersionId: bigint | undefined) => void) {
+    this.connection.onReducer("save_document_version", callback);
+  }
+
+  removeOnSaveDocumentVersion(callback: (ctx: ReducerEventContext, applicationId: bigint, docType: DocType, content: string, generator: string, parentVersionId: bigint | undefined) => void) {
+    this.connection.offReducer("save_document_version", callback);
+  }
+
+  setApplicationInventor(applicationId: bigint, inventor: Identity, position: number, contribution: string, contributionPercent: number | undefined) {
+    const __args = { applicationId, inventor, position, contribution, contributionPercent };
+    let __writer = new BinaryWriter(1024);
+    SetApplicationInventor.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_application_inventor", __argsBuffer, this.setCallReducerFlags.setApplicationInventorFlags);
+  }
+
+  onSetApplicationInventor(callback: (ctx: ReducerEventContext, applicationId: bigint, inventor: Identity, position: number, contribution: string, contributionPercent: number | undefined) => void) {
+    this.connection.onReducer("set_application_inventor", callback);
+  }
+
+  removeOnSetApplicationInventor(callback: (ctx: ReducerEventContext, applicationId: bigint, inventor: Identity, position: number, contribution: string, contributionPercent: number | undefined) => void) {
+    this.connection.offReducer("set_application_inventor", callback);
+  }
+
+  setFilingDetails(applicationId: bigint, filingType: FilingType, filingDate: Timestamp, priorityDate: Timestamp | undefined) {
+    const __args = { applicationId, filingType, filingDate, priorityDate };
+    let __writer = new BinaryWriter(1024);
+    SetFilingDetails.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_filing_details", __argsBuffer, this.setCallReducerFlags.setFilingDetailsFlags);
+  }
+
+  onSetFilingDetails(callback: (ctx: ReducerEventContext, applicationId: bigint, filingType: FilingType, filingDate: Timestamp, priorityDate: Timestamp | undefined) => void) {
+    this.connection.onReducer("set_filing_details", callback);
+  }
+
+  removeOnSetFilingDetails(callback: (ctx: ReducerEventContext, applicationId: bigint, filingType: FilingType, filingDate: Timestamp, priorityDate: Timestamp | undefined) => void) {
+    this.connection.offReducer("set_filing_details", callback);
+  }
+
+  setJobInterval(job: string, intervalSecs: bigint) {
+    const __args = { job, intervalSecs };
+    let __writer = new BinaryWriter(1024);
+    SetJobInterval.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_job_interval", __argsBuffer, this.setCallReducerFlags.setJobIntervalFlags);
+  }
+
+  onSetJobInterval(callback: (ctx: ReducerEventContext, job: string, intervalSecs: bigint) => void) {
+    this.connection.onReducer("set_job_interval", callback);
+  }
+
+  removeOnSetJobInterval(callback: (ctx: ReducerEventContext, job: string, intervalSecs: bigint) => void) {
+    this.connection.offReducer("set_job_interval", callback);
   }
 
-  respondConnection(connectionId: bigint, accept: boolean) {
-    const __args = { connectionId, accept };
+  setReminderLeadDays(leadDays: number[]) {
+    const __args = { leadDays };
     let __writer = new BinaryWriter(1024);
-    RespondConnection.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    SetReminderLeadDays.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("respond_connection", __argsBuffer, this.setCallReducerFlags.respondConnectionFlags);
+    this.connection.callReducer("set_reminder_lead_days", __argsBuffer, this.setCallReducerFlags.setReminderLeadDaysFlags);
   }
 
-  onRespondConnection(callback: (ctx: ReducerEventContext, connectionId: bigint, accept: boolean) => void) {
-    this.connection.onReducer("respond_connection", callback);
+  onSetReminderLeadDays(callback: (ctx: ReducerEventContext, leadDays: number[]) => void) {
+    this.connection.onReducer("set_reminder_lead_days", callback);
   }
 
-  removeOnRespondConnection(callback: (ctx: ReducerEventContext, connectionId: bigint, accept: boolean) => void) {
-    this.connection.offReducer("respond_connection", callback);
+  removeOnSetReminderLeadDays(callback: (ctx: ReducerEventContext, leadDays: number[]) => void) {
+    this.connection.offReducer("set_reminder_lead_days", callback);
   }
 
   startCollabSession(title: string) {
@@ -651,6 +2558,54 @@ export class RemoteReducers {
     this.connection.offReducer("submit_patent", callback);
   }
 
+  suggestClassifications(applicationId: bigint) {
+    const __args = { applicationId };
+    let __writer = new BinaryWriter(1024);
+    SuggestClassifications.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("suggest_classifications", __argsBuffer, this.setCallReducerFlags.suggestClassificationsFlags);
+  }
+
+  onSuggestClassifications(callback: (ctx: ReducerEventContext, applicationId: bigint) => void) {
+    this.connection.onReducer("suggest_classifications", callback);
+  }
+
+  removeOnSuggestClassifications(callback: (ctx: ReducerEventContext, applicationId: bigint) => void) {
+    this.connection.offReducer("suggest_classifications", callback);
+  }
+
+  unlinkFamily(linkId: bigint) {
+    const __args = { linkId };
+    let __writer = new BinaryWriter(1024);
+    UnlinkFamily.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("unlink_family", __argsBuffer, this.setCallReducerFlags.unlinkFamilyFlags);
+  }
+
+  onUnlinkFamily(callback: (ctx: ReducerEventContext, linkId: bigint) => void) {
+    this.connection.onReducer("unlink_family", callback);
+  }
+
+  removeOnUnlinkFamily(callback: (ctx: ReducerEventContext, linkId: bigint) => void) {
+    this.connection.offReducer("unlink_family", callback);
+  }
+
+  updateJurisdictionStatus(filingId: bigint, status: PatentStatus) {
+    const __args = { filingId, status };
+    let __writer = new BinaryWriter(1024);
+    UpdateJurisdictionStatus.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_jurisdiction_status", __argsBuffer, this.setCallReducerFlags.updateJurisdictionStatusFlags);
+  }
+
+  onUpdateJurisdictionStatus(callback: (ctx: ReducerEventContext, filingId: bigint, status: PatentStatus) => void) {
+    this.connection.onReducer("update_jurisdiction_status", callback);
+  }
+
+  removeOnUpdateJurisdictionStatus(callback: (ctx: ReducerEventContext, filingId: bigint, status: PatentStatus) => void) {
+    this.connection.offReducer("update_jurisdiction_status", callback);
+  }
+
   updatePatentStatus(applicationId: bigint, newStatus: PatentStatus) {
     const __args = { applicationId, newStatus };
     let __writer = new BinaryWriter(1024);
@@ -699,12 +2654,69 @@ export class RemoteReducers {
     this.connection.offReducer("upsert_document_generation", callback);
   }
 
+  verifyMerkleInclusion(notarizationId: bigint) {
+    const __args = { notarizationId };
+    let __writer = new BinaryWriter(1024);
+    VerifyMerkleInclusion.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("verify_merkle_inclusion", __argsBuffer, this.setCallReducerFlags.verifyMerkleInclusionFlags);
+  }
+
+  onVerifyMerkleInclusion(callback: (ctx: ReducerEventContext, notarizationId: bigint) => void) {
+    this.connection.onReducer("verify_merkle_inclusion", callback);
+  }
+
+  removeOnVerifyMerkleInclusion(callback: (ctx: ReducerEventContext, notarizationId: bigint) => void) {
+    this.connection.offReducer("verify_merkle_inclusion", callback);
+  }
+
+  verifyMerkleProof(batchId: bigint, digest: string, proof: MerkleProofStep[]) {
+    const __args = { batchId, digest, proof };
+    let __writer = new BinaryWriter(1024);
+    VerifyMerkleProof.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("verify_merkle_proof", __argsBuffer, this.setCallReducerFlags.verifyMerkleProofFlags);
+  }
+
+  onVerifyMerkleProof(callback: (ctx: ReducerEventContext, batchId: bigint, digest: string, proof: MerkleProofStep[]) => void) {
+    this.connection.onReducer("verify_merkle_proof", callback);
+  }
+
+  removeOnVerifyMerkleProof(callback: (ctx: ReducerEventContext, batchId: bigint, digest: string, proof: MerkleProofStep[]) => void) {
+    this.connection.offReducer("verify_merkle_proof", callback);
+  }
+
 }
 
 export class SetReducerFlags {
-  addBlockchainRecordFlags: CallReducerFlags = 'FullUpdate';
-  addBlockchainRecord(flags: CallReducerFlags) {
-    this.addBlockchainRecordFlags = flags;
+  acknowledgeReminderFlags: CallReducerFlags = 'FullUpdate';
+  acknowledgeReminder(flags: CallReducerFlags) {
+    this.acknowledgeReminderFlags = flags;
+  }
+
+  addAdminFlags: CallReducerFlags = 'FullUpdate';
+  addAdmin(flags: CallReducerFlags) {
+    this.addAdminFlags = flags;
+  }
+
+  addClassificationFlags: CallReducerFlags = 'FullUpdate';
+  addClassification(flags: CallReducerFlags) {
+    this.addClassificationFlags = flags;
+  }
+
+  addCoOwnerFlags: CallReducerFlags = 'FullUpdate';
+  addCoOwner(flags: CallReducerFlags) {
+    this.addCoOwnerFlags = flags;
+  }
+
+  addJurisdictionFilingFlags: CallReducerFlags = 'FullUpdate';
+  addJurisdictionFiling(flags: CallReducerFlags) {
+    this.addJurisdictionFilingFlags = flags;
+  }
+
+  addOrganizationMemberFlags: CallReducerFlags = 'FullUpdate';
+  addOrganizationMember(flags: CallReducerFlags) {
+    this.addOrganizationMemberFlags = flags;
   }
 
   addPriorArtResultFlags: CallReducerFlags = 'FullUpdate';
@@ -722,16 +2734,131 @@ export class SetReducerFlags {
     this.analyticsTickFlags = flags;
   }
 
+  anchorMerkleBatchFlags: CallReducerFlags = 'FullUpdate';
+  anchorMerkleBatch(flags: CallReducerFlags) {
+    this.anchorMerkleBatchFlags = flags;
+  }
+
+  anchorNotarizationFlags: CallReducerFlags = 'FullUpdate';
+  anchorNotarization(flags: CallReducerFlags) {
+    this.anchorNotarizationFlags = flags;
+  }
+
+  annotatePriorArtCitationFlags: CallReducerFlags = 'FullUpdate';
+  annotatePriorArtCitation(flags: CallReducerFlags) {
+    this.annotatePriorArtCitationFlags = flags;
+  }
+
+  bootstrapModuleFlags: CallReducerFlags = 'FullUpdate';
+  bootstrapModule(flags: CallReducerFlags) {
+    this.bootstrapModuleFlags = flags;
+  }
+
+  cancelBatchAnchoringFlags: CallReducerFlags = 'FullUpdate';
+  cancelBatchAnchoring(flags: CallReducerFlags) {
+    this.cancelBatchAnchoringFlags = flags;
+  }
+
+  claimNextJobFlags: CallReducerFlags = 'FullUpdate';
+  claimNextJob(flags: CallReducerFlags) {
+    this.claimNextJobFlags = flags;
+  }
+
   closeCollabSessionFlags: CallReducerFlags = 'FullUpdate';
   closeCollabSession(flags: CallReducerFlags) {
     this.closeCollabSessionFlags = flags;
   }
 
+  compactionTickFlags: CallReducerFlags = 'FullUpdate';
+  compactionTick(flags: CallReducerFlags) {
+    this.compactionTickFlags = flags;
+  }
+
+  completeDeadlineFlags: CallReducerFlags = 'FullUpdate';
+  completeDeadline(flags: CallReducerFlags) {
+    this.completeDeadlineFlags = flags;
+  }
+
+  completeJobFlags: CallReducerFlags = 'FullUpdate';
+  completeJob(flags: CallReducerFlags) {
+    this.completeJobFlags = flags;
+  }
+
+  computeDocumentDiffFlags: CallReducerFlags = 'FullUpdate';
+  computeDocumentDiff(flags: CallReducerFlags) {
+    this.computeDocumentDiffFlags = flags;
+  }
+
+  confirmClassificationFlags: CallReducerFlags = 'FullUpdate';
+  confirmClassification(flags: CallReducerFlags) {
+    this.confirmClassificationFlags = flags;
+  }
+
+  confirmInventorshipFlags: CallReducerFlags = 'FullUpdate';
+  confirmInventorship(flags: CallReducerFlags) {
+    this.confirmInventorshipFlags = flags;
+  }
+
   connectInventorFlags: CallReducerFlags = 'FullUpdate';
   connectInventor(flags: CallReducerFlags) {
     this.connectInventorFlags = flags;
   }
 
+  createOrganizationFlags: CallReducerFlags = 'FullUpdate';
+  createOrganization(flags: CallReducerFlags) {
+    this.createOrganizationFlags = flags;
+  }
+
+  disputeInventorshipFlags: CallReducerFlags = 'FullUpdate';
+  disputeInventorship(flags: CallReducerFlags) {
+    this.disputeInventorshipFlags = flags;
+  }
+
+  docketTickFlags: CallReducerFlags = 'FullUpdate';
+  docketTick(flags: CallReducerFlags) {
+    this.docketTickFlags = flags;
+  }
+
+  enqueueDocJobFlags: CallReducerFlags = 'FullUpdate';
+  enqueueDocJob(flags: CallReducerFlags) {
+    this.enqueueDocJobFlags = flags;
+  }
+
+  exportApplicationXmlFlags: CallReducerFlags = 'FullUpdate';
+  exportApplicationXml(flags: CallReducerFlags) {
+    this.exportApplicationXmlFlags = flags;
+  }
+
+  exportPortfolioFlags: CallReducerFlags = 'FullUpdate';
+  exportPortfolio(flags: CallReducerFlags) {
+    this.exportPortfolioFlags = flags;
+  }
+
+  failJobFlags: CallReducerFlags = 'FullUpdate';
+  failJob(flags: CallReducerFlags) {
+    this.failJobFlags = flags;
+  }
+
+  generateIdsFlags: CallReducerFlags = 'FullUpdate';
+  generateIds(flags: CallReducerFlags) {
+    this.generateIdsFlags = flags;
+  }
+
+  heartbeatJobFlags: CallReducerFlags = 'FullUpdate';
+  heartbeatJob(flags: CallReducerFlags) {
+    this.heartbeatJobFlags = flags;
+  }
+
+  importApplicationXmlFlags: CallReducerFlags = 'FullUpdate';
+  importApplicationXml(flags: CallReducerFlags) {
+    this.importApplicationXmlFlags = flags;
+  }
+
+  importPortfolioFlags: CallReducerFlags = 'FullUpdate';
+  importPortfolio(flags: CallReducerFlags) {
+    this.importPortfolioFlags = flags;
+  }
+
   joinCollabSessionFlags: CallReducerFlags = 'FullUpdate';
   joinCollabSession(flags: CallReducerFlags) {
     this.joinCollabSessionFlags = flags;
@@ -742,16 +2869,121 @@ export class SetReducerFlags {
     this.leaveCollabSessionFlags = flags;
   }
 
+  linkFamilyFlags: CallReducerFlags = 'FullUpdate';
+  linkFamily(flags: CallReducerFlags) {
+    this.linkFamilyFlags = flags;
+  }
+
+  maintenanceTickFlags: CallReducerFlags = 'FullUpdate';
+  maintenanceTick(flags: CallReducerFlags) {
+    this.maintenanceTickFlags = flags;
+  }
+
+  merkleBatchTickFlags: CallReducerFlags = 'FullUpdate';
+  merkleBatchTick(flags: CallReducerFlags) {
+    this.merkleBatchTickFlags = flags;
+  }
+
   monitorTickFlags: CallReducerFlags = 'FullUpdate';
   monitorTick(flags: CallReducerFlags) {
     this.monitorTickFlags = flags;
   }
 
+  notarizeApplicationFlags: CallReducerFlags = 'FullUpdate';
+  notarizeApplication(flags: CallReducerFlags) {
+    this.notarizeApplicationFlags = flags;
+  }
+
+  pauseJobFlags: CallReducerFlags = 'FullUpdate';
+  pauseJob(flags: CallReducerFlags) {
+    this.pauseJobFlags = flags;
+  }
+
+  pinFiledVersionFlags: CallReducerFlags = 'FullUpdate';
+  pinFiledVersion(flags: CallReducerFlags) {
+    this.pinFiledVersionFlags = flags;
+  }
+
+  purchaseOfficeActionExtensionFlags: CallReducerFlags = 'FullUpdate';
+  purchaseOfficeActionExtension(flags: CallReducerFlags) {
+    this.purchaseOfficeActionExtensionFlags = flags;
+  }
+
+  reclaimDocJobLeasesFlags: CallReducerFlags = 'FullUpdate';
+  reclaimDocJobLeases(flags: CallReducerFlags) {
+    this.reclaimDocJobLeasesFlags = flags;
+  }
+
+  recordGrantFlags: CallReducerFlags = 'FullUpdate';
+  recordGrant(flags: CallReducerFlags) {
+    this.recordGrantFlags = flags;
+  }
+
+  recordMaintenancePaymentFlags: CallReducerFlags = 'FullUpdate';
+  recordMaintenancePayment(flags: CallReducerFlags) {
+    this.recordMaintenancePaymentFlags = flags;
+  }
+
+  recordOfficeActionFlags: CallReducerFlags = 'FullUpdate';
+  recordOfficeAction(flags: CallReducerFlags) {
+    this.recordOfficeActionFlags = flags;
+  }
+
+  recordOfficeActionResponseFlags: CallReducerFlags = 'FullUpdate';
+  recordOfficeActionResponse(flags: CallReducerFlags) {
+    this.recordOfficeActionResponseFlags = flags;
+  }
+
+  regenerateDocumentFlags: CallReducerFlags = 'FullUpdate';
+  regenerateDocument(flags: CallReducerFlags) {
+    this.regenerateDocumentFlags = flags;
+  }
+
+  registerDocWorkerFlags: CallReducerFlags = 'FullUpdate';
+  registerDocWorker(flags: CallReducerFlags) {
+    this.registerDocWorkerFlags = flags;
+  }
+
   registerInventorFlags: CallReducerFlags = 'FullUpdate';
   registerInventor(flags: CallReducerFlags) {
     this.registerInventorFlags = flags;
   }
 
+  removeAdminFlags: CallReducerFlags = 'FullUpdate';
+  removeAdmin(flags: CallReducerFlags) {
+    this.removeAdminFlags = flags;
+  }
+
+  removeApplicationInventorFlags: CallReducerFlags = 'FullUpdate';
+  removeApplicationInventor(flags: CallReducerFlags) {
+    this.removeApplicationInventorFlags = flags;
+  }
+
+  removeClassificationFlags: CallReducerFlags = 'FullUpdate';
+  removeClassification(flags: CallReducerFlags) {
+    this.removeClassificationFlags = flags;
+  }
+
+  removeCoOwnerFlags: CallReducerFlags = 'FullUpdate';
+  removeCoOwner(flags: CallReducerFlags) {
+    this.removeCoOwnerFlags = flags;
+  }
+
+  removeDocWorkerFlags: CallReducerFlags = 'FullUpdate';
+  removeDocWorker(flags: CallReducerFlags) {
+    this.removeDocWorkerFlags = flags;
+  }
+
+  removeOrganizationMemberFlags: CallReducerFlags = 'FullUpdate';
+  removeOrganizationMember(flags: CallReducerFlags) {
+    this.removeOrganizationMemberFlags = flags;
+  }
+
+  requestBatchAnchoringFlags: CallReducerFlags = 'FullUpdate';
+  requestBatchAnchoring(flags: CallReducerFlags) {
+    this.requestBatchAnchoringFlags = flags;
+  }
+
   resolveAlertFlags: CallReducerFlags = 'FullUpdate';
   resolveAlert(flags: CallReducerFlags) {
     this.resolveAlertFlags = flags;
@@ -762,6 +2994,41 @@ export class SetReducerFlags {
     this.respondConnectionFlags = flags;
   }
 
+  resumeJobFlags: CallReducerFlags = 'FullUpdate';
+  resumeJob(flags: CallReducerFlags) {
+    this.resumeJobFlags = flags;
+  }
+
+  reviewPriorArtFlags: CallReducerFlags = 'FullUpdate';
+  reviewPriorArt(flags: CallReducerFlags) {
+    this.reviewPriorArtFlags = flags;
+  }
+
+  saveDocumentVersionFlags: CallReducerFlags = 'FullUpdate';
+  saveDocumentVersion(flags: CallReducerFlags) {
+    this.saveDocumentVersionFlags = flags;
+  }
+
+  setApplicationInventorFlags: CallReducerFlags = 'FullUpdate';
+  setApplicationInventor(flags: CallReducerFlags) {
+    this.setApplicationInventorFlags = flags;
+  }
+
+  setFilingDetailsFlags: CallReducerFlags = 'FullUpdate';
+  setFilingDetails(flags: CallReducerFlags) {
+    this.setFilingDetailsFlags = flags;
+  }
+
+  setJobIntervalFlags: CallReducerFlags = 'FullUpdate';
+  setJobInterval(flags: CallReducerFlags) {
+    this.setJobIntervalFlags = flags;
+  }
+
+  setReminderLeadDaysFlags: CallReducerFlags = 'FullUpdate';
+  setReminderLeadDays(flags: CallReducerFlags) {
+    this.setReminderLeadDaysFlags = flags;
+  }
+
   startCollabSessionFlags: CallReducerFlags = 'FullUpdate';
   startCollabSession(flags: CallReducerFlags) {
     this.startCollabSessionFlags = flags;
@@ -772,6 +3039,21 @@ export class SetReducerFlags {
     this.submitPatentFlags = flags;
   }
 
+  suggestClassificationsFlags: CallReducerFlags = 'FullUpdate';
+  suggestClassifications(flags: CallReducerFlags) {
+    this.suggestClassificationsFlags = flags;
+  }
+
+  unlinkFamilyFlags: CallReducerFlags = 'FullUpdate';
+  unlinkFamily(flags: CallReducerFlags) {
+    this.unlinkFamilyFlags = flags;
+  }
+
+  updateJurisdictionStatusFlags: CallReducerFlags = 'FullUpdate';
+  updateJurisdictionStatus(flags: CallReducerFlags) {
+    this.updateJurisdictionStatusFlags = flags;
+  }
+
   updatePatentStatusFlags: CallReducerFlags = 'FullUpdate';
   updatePatentStatus(flags: CallReducerFlags) {
     this.updatePatentStatusFlags = flags;
@@ -787,19 +3069,53 @@ export class SetReducerFlags {
     this.upsertDocumentGenerationFlags = flags;
   }
 
+  verifyMerkleInclusionFlags: CallReducerFlags = 'FullUpdate';
+  verifyMerkleInclusion(flags: CallReducerFlags) {
+    this.verifyMerkleInclusionFlags = flags;
+  }
+
+  verifyMerkleProofFlags: CallReducerFlags = 'FullUpdate';
+  verifyMerkleProof(flags: CallReducerFlags) {
+    this.verifyMerkleProofFlags = flags;
+  }
+
 }
 
 export class RemoteTables {
   constructor(private connection: DbConnectionImpl) {}
 
+  get admin(): AdminTableHandle {
+    return new AdminTableHandle(this.connection.clientCache.getOrCreateTable<Admin>(REMOTE_MODULE.tables.admin));
+  }
+
   get analyticsSchedule(): AnalyticsScheduleTableHandle {
     return new AnalyticsScheduleTableHandle(this.connection.clientCache.getOrCreateTable<AnalyticsSchedule>(REMOTE_MODULE.tables.analytics_schedule));
   }
 
+  get applicationEvent(): ApplicationEventTableHandle {
+    return new ApplicationEventTableHandle(this.connection.clientCache.getOrCreateTable<ApplicationEvent>(REMOTE_MODULE.tables.application_event));
+  }
+
+  get applicationInventor(): ApplicationInventorTableHandle {
+    return new ApplicationInventorTableHandle(this.connection.clientCache.getOrCreateTable<ApplicationInventor>(REMOTE_MODULE.tables.application_inventor));
+  }
+
   get blockchainRecord(): BlockchainRecordTableHandle {
     return new BlockchainRecordTableHandle(this.connection.clientCache.getOrCreateTable<BlockchainRecord>(REMOTE_MODULE.tables.blockchain_record));
   }
 
+  get blockchainRecordBatch(): BlockchainRecordBatchTableHandle {
+    return new BlockchainRecordBatchTableHandle(this.connection.clientCache.getOrCreateTable<BlockchainRecordBatch>(REMOTE_MODULE.tables.blockchain_record_batch));
+  }
+
+  get classification(): ClassificationTableHandle {
+    return new ClassificationTableHandle(this.connection.clientCache.getOrCreateTable<Classification>(REMOTE_MODULE.tables.classification));
+  }
+
+  get coOwnerGrant(): CoOwnerGrantTableHandle {
+    return new CoOwnerGrantTableHandle(this.connection.clientCache.getOrCreateTable<CoOwnerGrant>(REMOTE_MODULE.tables.co_owner_grant));
+  }
+
   get collabParticipant(): CollabParticipantTableHandle {
     return new CollabParticipantTableHandle(this.connection.clientCache.getOrCreateTable<SessionParticipant>(REMOTE_MODULE.tables.collab_participant));
   }
@@ -808,10 +3124,86 @@ export class RemoteTables {
     return new CollabSessionTableHandle(this.connection.clientCache.getOrCreateTable<CollaborationSession>(REMOTE_MODULE.tables.collab_session));
   }
 
+  get compactionSchedule(): CompactionScheduleTableHandle {
+    return new CompactionScheduleTableHandle(this.connection.clientCache.getOrCreateTable<CompactionSchedule>(REMOTE_MODULE.tables.compaction_schedule));
+  }
+
+  get deadline(): DeadlineTableHandle {
+    return new DeadlineTableHandle(this.connection.clientCache.getOrCreateTable<Deadline>(REMOTE_MODULE.tables.deadline));
+  }
+
+  get deadlineReminder(): DeadlineReminderTableHandle {
+    return new DeadlineReminderTableHandle(this.connection.clientCache.getOrCreateTable<DeadlineReminder>(REMOTE_MODULE.tables.deadline_reminder));
+  }
+
+  get docGenAttempt(): DocGenAttemptTableHandle {
+    return new DocGenAttemptTableHandle(this.connection.clientCache.getOrCreateTable<DocGenAttempt>(REMOTE_MODULE.tables.doc_gen_attempt));
+  }
+
+  get docGenMetric(): DocGenMetricTableHandle {
+    return new DocGenMetricTableHandle(this.connection.clientCache.getOrCreateTable<DocGenMetric>(REMOTE_MODULE.tables.doc_gen_metric));
+  }
+
+  get docJob(): DocJobTableHandle {
+    return new DocJobTableHandle(this.connection.clientCache.getOrCreateTable<DocJob>(REMOTE_MODULE.tables.doc_job));
+  }
+
+  get docLeaseSchedule(): DocLeaseScheduleTableHandle {
+    return new DocLeaseScheduleTableHandle(this.connection.clientCache.getOrCreateTable<DocLeaseSchedule>(REMOTE_MODULE.tables.doc_lease_schedule));
+  }
+
+  get docWorker(): DocWorkerTableHandle {
+    return new DocWorkerTableHandle(this.connection.clientCache.getOrCreateTable<DocWorker>(REMOTE_MODULE.tables.doc_worker));
+  }
+
+  get docketSchedule(): DocketScheduleTableHandle {
+    return new DocketScheduleTableHandle(this.connection.clientCache.getOrCreateTable<DocketSchedule>(REMOTE_MODULE.tables.docket_schedule));
+  }
+
+  get documentDiff(): DocumentDiffTableHandle {
+    return new DocumentDiffTableHandle(this.connection.clientCache.getOrCreateTable<DocumentDiff>(REMOTE_MODULE.tables.document_diff));
+  }
+
   get documentGeneration(): DocumentGenerationTableHandle {
     return new DocumentGenerationTableHandle(this.connection.clientCache.getOrCreateTable<DocumentGeneration>(REMOTE_MODULE.tables.document_generation));
   }
 
+  get documentVersion(): DocumentVersionTableHandle {
+    return new DocumentVersionTableHandle(this.connection.clientCache.getOrCreateTable<DocumentVersion>(REMOTE_MODULE.tables.document_version));
+  }
+
+  get exportArtifact(): ExportArtifactTableHandle {
+    return new ExportArtifactTableHandle(this.connection.clientCache.getOrCreateTable<ExportArtifact>(REMOTE_MODULE.tables.export_artifact));
+  }
+
+  get familyLink(): FamilyLinkTableHandle {
+    return new FamilyLinkTableHandle(this.connection.clientCache.getOrCreateTable<FamilyLink>(REMOTE_MODULE.tables.family_link));
+  }
+
+  get familyNode(): FamilyNodeTableHandle {
+    return new FamilyNodeTableHandle(this.connection.clientCache.getOrCreateTable<FamilyNode>(REMOTE_MODULE.tables.family_node));
+  }
+
+  get filingDetail(): FilingDetailTableHandle {
+    return new FilingDetailTableHandle(this.connection.clientCache.getOrCreateTable<FilingDetail>(REMOTE_MODULE.tables.filing_detail));
+  }
+
+  get filingReadiness(): FilingReadinessTableHandle {
+    return new FilingReadinessTableHandle(this.connection.clientCache.getOrCreateTable<FilingReadiness>(REMOTE_MODULE.tables.filing_readiness));
+  }
+
+  get grantRecord(): GrantRecordTableHandle {
+    return new GrantRecordTableHandle(this.connection.clientCache.getOrCreateTable<GrantRecord>(REMOTE_MODULE.tables.grant_record));
+  }
+
+  get idsFiling(): IdsFilingTableHandle {
+    return new IdsFilingTableHandle(this.connection.clientCache.getOrCreateTable<IdsFiling>(REMOTE_MODULE.tables.ids_filing));
+  }
+
+  get importReport(): ImportReportTableHandle {
+    return new ImportReportTableHandle(this.connection.clientCache.getOrCreateTable<ImportReport>(REMOTE_MODULE.tables.import_report));
+  }
+
   get infringementAlert(): InfringementAlertTableHandle {
     return new InfringementAlertTableHandle(this.connection.clientCache.getOrCreateTable<InfringementAlert>(REMOTE_MODULE.tables.infringement_alert));
   }
@@ -824,29 +3216,97 @@ export class RemoteTables {
     return new InventorConnectionTableHandle(this.connection.clientCache.getOrCreateTable<InventorConnection>(REMOTE_MODULE.tables.inventor_connection));
   }
 
+  get jurisdictionFiling(): JurisdictionFilingTableHandle {
+    return new JurisdictionFilingTableHandle(this.connection.clientCache.getOrCreateTable<JurisdictionFiling>(REMOTE_MODULE.tables.jurisdiction_filing));
+  }
+
+  get maintenanceFee(): MaintenanceFeeTableHandle {
+    return new MaintenanceFeeTableHandle(this.connection.clientCache.getOrCreateTable<MaintenanceFee>(REMOTE_MODULE.tables.maintenance_fee));
+  }
+
+  get maintenanceSchedule(): MaintenanceScheduleTableHandle {
+    return new MaintenanceScheduleTableHandle(this.connection.clientCache.getOrCreateTable<MaintenanceSchedule>(REMOTE_MODULE.tables.maintenance_schedule));
+  }
+
+  get marketTrendRollup(): MarketTrendRollupTableHandle {
+    return new MarketTrendRollupTableHandle(this.connection.clientCache.getOrCreateTable<MarketTrendRollup>(REMOTE_MODULE.tables.market_trend_rollup));
+  }
+
   get marketTrendSnapshot(): MarketTrendSnapshotTableHandle {
     return new MarketTrendSnapshotTableHandle(this.connection.clientCache.getOrCreateTable<MarketTrendSnapshot>(REMOTE_MODULE.tables.market_trend_snapshot));
   }
 
+  get merkleBatch(): MerkleBatchTableHandle {
+    return new MerkleBatchTableHandle(this.connection.clientCache.getOrCreateTable<MerkleBatch>(REMOTE_MODULE.tables.merkle_batch));
+  }
+
+  get merkleSchedule(): MerkleScheduleTableHandle {
+    return new MerkleScheduleTableHandle(this.connection.clientCache.getOrCreateTable<MerkleSchedule>(REMOTE_MODULE.tables.merkle_schedule));
+  }
+
+  get moduleConfig(): ModuleConfigTableHandle {
+    return new ModuleConfigTableHandle(this.connection.clientCache.getOrCreateTable<ModuleConfig>(REMOTE_MODULE.tables.module_config));
+  }
+
   get monitoringSchedule(): MonitoringScheduleTableHandle {
     return new MonitoringScheduleTableHandle(this.connection.clientCache.getOrCreateTable<MonitoringSchedule>(REMOTE_MODULE.tables.monitoring_schedule));
   }
 
+  get notarization(): NotarizationTableHandle {
+    return new NotarizationTableHandle(this.connection.clientCache.getOrCreateTable<Notarization>(REMOTE_MODULE.tables.notarization));
+  }
+
+  get officeAction(): OfficeActionTableHandle {
+    return new OfficeActionTableHandle(this.connection.clientCache.getOrCreateTable<OfficeAction>(REMOTE_MODULE.tables.office_action));
+  }
+
+  get organization(): OrganizationTableHandle {
+    return new OrganizationTableHandle(this.connection.clientCache.getOrCreateTable<Organization>(REMOTE_MODULE.tables.organization));
+  }
+
+  get organizationMember(): OrganizationMemberTableHandle {
+    return new OrganizationMemberTableHandle(this.connection.clientCache.getOrCreateTable<OrganizationMember>(REMOTE_MODULE.tables.organization_member));
+  }
+
   get patentApplication(): PatentApplicationTableHandle {
     return new PatentApplicationTableHandle(this.connection.clientCache.getOrCreateTable<PatentApplication>(REMOTE_MODULE.tables.patent_application));
   }
 
+  get pipelineMetric(): PipelineMetricTableHandle {
+    return new PipelineMetricTableHandle(this.connection.clientCache.getOrCreateTable<PipelineMetric>(REMOTE_MODULE.tables.pipeline_metric));
+  }
+
   get portfolioEntry(): PortfolioEntryTableHandle {
     return new PortfolioEntryTableHandle(this.connection.clientCache.getOrCreateTable<PatentPortfolioEntry>(REMOTE_MODULE.tables.portfolio_entry));
   }
 
+  get portfolioTrendSnapshot(): PortfolioTrendSnapshotTableHandle {
+    return new PortfolioTrendSnapshotTableHandle(this.connection.clientCache.getOrCreateTable<PortfolioTrendSnapshot>(REMOTE_MODULE.tables.portfolio_trend_snapshot));
+  }
+
+  get priorArtCitation(): PriorArtCitationTableHandle {
+    return new PriorArtCitationTableHandle(this.connection.clientCache.getOrCreateTable<PriorArtCitation>(REMOTE_MODULE.tables.prior_art_citation));
+  }
+
   get priorArtResult(): PriorArtResultTableHandle {
     return new PriorArtResultTableHandle(this.connection.clientCache.getOrCreateTable<PriorArtResult>(REMOTE_MODULE.tables.prior_art_result));
   }
 
+  get priorArtReview(): PriorArtReviewTableHandle {
+    return new PriorArtReviewTableHandle(this.connection.clientCache.getOrCreateTable<PriorArtReview>(REMOTE_MODULE.tables.prior_art_review));
+  }
+
+  get reminderLeadTime(): ReminderLeadTimeTableHandle {
+    return new ReminderLeadTimeTableHandle(this.connection.clientCache.getOrCreateTable<ReminderLeadTime>(REMOTE_MODULE.tables.reminder_lead_time));
+  }
+
   get stageProgress(): StageProgressTableHandle {
     return new StageProgressTableHandle(this.connection.clientCache.getOrCreateTable<StageProgress>(REMOTE_MODULE.tables.stage_progress));
   }
+
+  get stageTransition(): StageTransitionTableHandle {
+    return new StageTransitionTableHandle(this.connection.clientCache.getOrCreateTable<StageTransition>(REMOTE_MODULE.tables.stage_transition));
+  }
 }
 
 export class SubscriptionBuilder extends SubscriptionBuilderImpl<RemoteTables, RemoteReducers, SetReducerFlags> { }